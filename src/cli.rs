@@ -0,0 +1,230 @@
+use crate::coverage::MaxUnresolved;
+use crate::parse::ScssOptions;
+use crate::theme::{size_px, BaseTheme, Dialect, MatchOptions, SpacingMatch};
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use std::path::{Path, PathBuf};
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "rewind-card",
+    version,
+    about = "Convert CSS property to Tailwind-like CSS class (tailwind-token)"
+)]
+pub struct Cli {
+    /// more log output, repeat for more detail (-v, -vv)
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    /// only print errors
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+impl Cli {
+    /// -1 for quiet, 0 for default, 1.. for each `-v`
+    pub fn verbosity(&self) -> i8 {
+        if self.quiet {
+            return -1;
+        }
+        self.verbose as i8
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// convert css files / directories into tailwind token
+    Convert(Box<ConvertArgs>),
+    /// print the loaded preset token tables
+    Preset(PresetArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct ConvertArgs {
+    /// css files or directories to convert, `-` reads the css from stdin
+    #[arg(required = true)]
+    pub inputs: Vec<PathBuf>,
+
+    /// glob of the files to convert inside the input directories, relative to the directory
+    /// [default: **/*.{css,scss,sass}]
+    #[arg(short, long = "include", value_name = "GLOB")]
+    pub include: Vec<String>,
+
+    /// glob of the files to skip inside the input directories, e.g. `vendor/**`
+    #[arg(short, long = "exclude", value_name = "GLOB")]
+    pub exclude: Vec<String>,
+
+    /// output directory, or output file when converting a single file, `-` writes to stdout
+    /// [default: ./out-tw-token, stdout when reading stdin]
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// output format
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Json)]
+    pub format: OutputFormat,
+
+    /// theme built into the binary the presets are merged on: `custom` (the tables of
+    /// `preset/`), `tailwind` (tailwind v3 defaults), `uno` (unocss preset-uno defaults)
+    /// or `none`
+    #[arg(long, value_name = "THEME", default_value_t = BaseTheme::Custom)]
+    pub base_theme: BaseTheme,
+
+    /// preset directory, a single preset csv, or a theme config, merged on the base theme,
+    /// can be repeated
+    #[arg(short, long, value_name = "DIR|FILE")]
+    pub preset: Vec<PathBuf>,
+
+    /// how a length is matched to the spacing / size scales of the theme: `exact`, a
+    /// tolerance like `2px` / `0.125rem` for the nearest step, or `arbitrary` for always
+    /// `p-[13px]`. a length off the scale becomes an arbitrary value
+    #[arg(long, value_name = "MODE", default_value_t = SpacingMatch::Exact)]
+    pub spacing_match: SpacingMatch,
+
+    /// the largest CIEDE2000 distance of a color to the color token it is matched to,
+    /// about 1 is a difference an eye can just see, `0` only matches the same color. an
+    /// approximate match is listed in `approximate_colors` of the output
+    #[arg(long, value_name = "DELTA_E", default_value_t = 2f32)]
+    pub color_delta_e: f32,
+
+    /// utility syntax of the tokens: `uno` and `tailwind` write the opacity of a color as
+    /// `bg-red-500/50`, `legacy` (tailwind v2 / windi) as a separate `bg-opacity-50`
+    #[arg(long, value_name = "DIALECT", default_value_t = Dialect::Uno)]
+    pub dialect: Dialect,
+
+    /// the largest difference of a media query width to a breakpoint of the theme, like
+    /// `0.1px` / `0.01rem`, `0` only matches the same width. a width off the breakpoints
+    /// becomes an arbitrary `min-[600px]:` / `max-[37.5rem]:` variant
+    #[arg(long, value_name = "LENGTH", default_value = "0", value_parser = parse_tolerance)]
+    pub breakpoint_tolerance: f32,
+
+    /// rewrite the rules of a selector and its max-width rules into mobile-first tokens,
+    /// `hidden lt-md:block` becomes `block md:hidden`
+    #[arg(long)]
+    pub mobile_first: bool,
+
+    /// extra directory searched by scss `@use` / `@import`, can be repeated
+    #[arg(short = 'I', long = "load-path", value_name = "DIR")]
+    pub load_path: Vec<PathBuf>,
+
+    /// silence scss `@warn` and `@debug` output
+    #[arg(long)]
+    pub scss_quiet: bool,
+
+    /// layer group name of the output, the file name is used by default
+    #[arg(short, long)]
+    pub layer: Option<String>,
+
+    /// syntax of the stylesheet read from stdin
+    #[arg(long, value_enum, default_value_t = StdinSyntax::Css)]
+    pub stdin_syntax: StdinSyntax,
+
+    /// write the coverage report of the converted declarations,
+    /// json when the path ends with `.json`, `-` prints the table to stderr
+    #[arg(long, value_name = "PATH")]
+    pub coverage: Option<PathBuf>,
+
+    /// write the custom properties of `:root` / `html` of the converted files as a theme
+    /// config json, the colors and lengths are added with `theme.extend`, use it with `-p`
+    #[arg(long, value_name = "PATH")]
+    pub vars_theme: Option<PathBuf>,
+
+    /// number of files converted at the same time [default: number of cpus]
+    #[arg(short, long, value_name = "N")]
+    pub jobs: Option<usize>,
+
+    /// keep running, and re-convert a file when it changes
+    #[arg(short, long)]
+    pub watch: bool,
+
+    /// exit with an error when a file fails to convert or any value is left unresolved,
+    /// same as `--max-unresolved 0`
+    #[arg(long, conflicts_with = "max_unresolved")]
+    pub strict: bool,
+
+    /// exit with an error when a file fails to convert or more values than this are left
+    /// unresolved (dropped declarations, unmatched colors, unresolved media queries and
+    /// `var()` without a value), a count like `10` or a percentage of the declarations like
+    /// `5%`
+    #[arg(long, value_name = "COUNT|PERCENT")]
+    pub max_unresolved: Option<MaxUnresolved>,
+}
+
+impl ConvertArgs {
+    pub fn is_stdin(&self) -> bool {
+        self.inputs.len() == 1 && self.inputs[0] == Path::new("-")
+    }
+
+    /// the matching options of the theme
+    pub fn match_options(&self) -> MatchOptions {
+        MatchOptions {
+            spacing: self.spacing_match,
+            color_delta_e: self.color_delta_e,
+            breakpoint_tolerance: self.breakpoint_tolerance,
+        }
+    }
+
+    /// the threshold of `--strict` / `--max-unresolved`, `None` when neither is given
+    pub fn max_unresolved(&self) -> Option<MaxUnresolved> {
+        if self.strict {
+            return Some(MaxUnresolved::Count(0));
+        }
+        self.max_unresolved
+    }
+
+    pub fn scss_options(&self) -> ScssOptions {
+        ScssOptions {
+            load_paths: self.load_path.to_owned(),
+            indented_syntax: false,
+            quiet: self.scss_quiet,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct PresetArgs {
+    /// theme built into the binary the presets are merged on: `custom` (the tables of
+    /// `preset/`), `tailwind` (tailwind v3 defaults), `uno` (unocss preset-uno defaults)
+    /// or `none`
+    #[arg(long, value_name = "THEME", default_value_t = BaseTheme::Custom)]
+    pub base_theme: BaseTheme,
+
+    /// preset directory, a single preset csv, or a theme config, merged on the base theme,
+    /// can be repeated
+    #[arg(short, long, value_name = "DIR|FILE")]
+    pub preset: Vec<PathBuf>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum StdinSyntax {
+    Css,
+    Scss,
+    Sass,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum OutputFormat {
+    /// pretty printed json
+    Json,
+    /// single line json
+    JsonCompact,
+    /// one line per rule, `selector { tokens }`
+    Text,
+}
+
+impl OutputFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Json | OutputFormat::JsonCompact => "json",
+            OutputFormat::Text => "txt",
+        }
+    }
+}
+
+/// a px / rem length in px, `0` is allowed without a unit
+fn parse_tolerance(s: &str) -> Result<f32, String> {
+    size_px(s)
+        .filter(|px| *px >= 0f32)
+        .ok_or(format!("`{}` is not a length like `0.1px` / `0.01rem`", s))
+}
@@ -0,0 +1,210 @@
+/// a color in CIELAB, D65 white point
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Lab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+fn srgb_to_linear(channel: u8) -> f64 {
+    let c = channel as f64 / 255f64;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn lab_f(t: f64) -> f64 {
+    let delta: f64 = 6f64 / 29f64;
+    if t > delta.powi(3) {
+        t.cbrt()
+    } else {
+        t / (3f64 * delta * delta) + 4f64 / 29f64
+    }
+}
+
+impl Lab {
+    pub fn from_rgb(red: u8, green: u8, blue: u8) -> Lab {
+        let (r, g, b) = (
+            srgb_to_linear(red),
+            srgb_to_linear(green),
+            srgb_to_linear(blue),
+        );
+        // linear srgb to xyz, relative to the D65 white
+        let x = (0.4124564 * r + 0.3575761 * g + 0.1804375 * b) / 0.95047;
+        let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+        let z = (0.0193339 * r + 0.119192 * g + 0.9503041 * b) / 1.08883;
+        let (fx, fy, fz) = (lab_f(x), lab_f(y), lab_f(z));
+        Lab {
+            l: 116f64 * fy - 16f64,
+            a: 500f64 * (fx - fy),
+            b: 200f64 * (fy - fz),
+        }
+    }
+
+    /// the CIEDE2000 difference, about 1 is the smallest difference an eye can see,
+    /// 0 is the same color
+    pub fn delta_e(&self, other: &Lab) -> f64 {
+        let (l1, a1, b1) = (self.l, self.a, self.b);
+        let (l2, a2, b2) = (other.l, other.a, other.b);
+
+        let c1 = a1.hypot(b1);
+        let c2 = a2.hypot(b2);
+        let c_mean7 = ((c1 + c2) / 2f64).powi(7);
+        let g = 0.5 * (1f64 - (c_mean7 / (c_mean7 + 25f64.powi(7))).sqrt());
+        let a1p = a1 * (1f64 + g);
+        let a2p = a2 * (1f64 + g);
+        let c1p = a1p.hypot(b1);
+        let c2p = a2p.hypot(b2);
+        let hue = |b: f64, ap: f64| {
+            if b == 0f64 && ap == 0f64 {
+                0f64
+            } else {
+                b.atan2(ap).to_degrees().rem_euclid(360f64)
+            }
+        };
+        let h1p = hue(b1, a1p);
+        let h2p = hue(b2, a2p);
+
+        let dl = l2 - l1;
+        let dc = c2p - c1p;
+        let dh = if c1p * c2p == 0f64 {
+            0f64
+        } else if (h2p - h1p).abs() <= 180f64 {
+            h2p - h1p
+        } else if h2p - h1p > 180f64 {
+            h2p - h1p - 360f64
+        } else {
+            h2p - h1p + 360f64
+        };
+        let dh_big = 2f64 * (c1p * c2p).sqrt() * (dh / 2f64).to_radians().sin();
+
+        let l_mean = (l1 + l2) / 2f64;
+        let c_mean = (c1p + c2p) / 2f64;
+        let h_mean = if c1p * c2p == 0f64 {
+            h1p + h2p
+        } else if (h1p - h2p).abs() <= 180f64 {
+            (h1p + h2p) / 2f64
+        } else if h1p + h2p < 360f64 {
+            (h1p + h2p + 360f64) / 2f64
+        } else {
+            (h1p + h2p - 360f64) / 2f64
+        };
+
+        let t = 1f64 - 0.17 * (h_mean - 30f64).to_radians().cos()
+            + 0.24 * (2f64 * h_mean).to_radians().cos()
+            + 0.32 * (3f64 * h_mean + 6f64).to_radians().cos()
+            - 0.20 * (4f64 * h_mean - 63f64).to_radians().cos();
+        let d_theta = 30f64 * (-((h_mean - 275f64) / 25f64).powi(2)).exp();
+        let c_mean7 = c_mean.powi(7);
+        let r_c = 2f64 * (c_mean7 / (c_mean7 + 25f64.powi(7))).sqrt();
+        let l_mean50 = (l_mean - 50f64).powi(2);
+        let s_l = 1f64 + 0.015 * l_mean50 / (20f64 + l_mean50).sqrt();
+        let s_c = 1f64 + 0.045 * c_mean;
+        let s_h = 1f64 + 0.015 * c_mean * t;
+        let r_t = -(2f64 * d_theta).to_radians().sin() * r_c;
+
+        ((dl / s_l).powi(2)
+            + (dc / s_c).powi(2)
+            + (dh_big / s_h).powi(2)
+            + r_t * (dc / s_c) * (dh_big / s_h))
+            .sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lab(l: f64, a: f64, b: f64) -> Lab {
+        Lab { l, a, b }
+    }
+
+    #[test]
+    fn delta_e_matches_the_ciede2000_test_data() {
+        // pairs of Sharma, Wu and Dalal, "The CIEDE2000 color-difference formula:
+        // implementation notes, supplementary test data, and mathematical observations" (2005)
+        let pairs = [
+            (
+                lab(50.0, 2.6772, -79.7751),
+                lab(50.0, 0.0, -82.7485),
+                2.0425,
+            ),
+            (
+                lab(50.0, -1.3802, -84.2814),
+                lab(50.0, 0.0, -82.7485),
+                1.0000,
+            ),
+            (lab(50.0, 0.0, 0.0), lab(50.0, -1.0, 2.0), 2.3669),
+            (lab(50.0, 2.49, -0.001), lab(50.0, -2.49, 0.0009), 7.1792),
+            (lab(50.0, 2.49, -0.001), lab(50.0, -2.49, 0.0011), 7.2195),
+            (lab(50.0, -0.001, 2.49), lab(50.0, 0.0009, -2.49), 4.8045),
+            (lab(50.0, -0.001, 2.49), lab(50.0, 0.0011, -2.49), 4.7461),
+            (lab(50.0, 2.5, 0.0), lab(50.0, 0.0, -2.5), 4.3065),
+            (lab(50.0, 2.5, 0.0), lab(73.0, 25.0, -18.0), 27.1492),
+            (
+                lab(60.2574, -34.0099, 36.2677),
+                lab(60.4626, -34.1751, 39.4387),
+                1.2644,
+            ),
+            (
+                lab(22.7233, 20.0904, -46.6940),
+                lab(23.0331, 14.9730, -42.5619),
+                2.0373,
+            ),
+            (
+                lab(6.7747, -0.2908, -2.4247),
+                lab(5.8714, -0.0985, -2.2286),
+                0.6377,
+            ),
+            (
+                lab(2.0776, 0.0795, -1.1350),
+                lab(0.9033, -0.0636, -0.5514),
+                0.9082,
+            ),
+        ];
+        for (first, second, expected) in pairs {
+            let delta = first.delta_e(&second);
+            assert!(
+                (delta - expected).abs() < 1e-4,
+                "{:?} {:?}: {} != {}",
+                first,
+                second,
+                delta,
+                expected
+            );
+            // the difference is symmetric
+            assert!((second.delta_e(&first) - delta).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn same_color_has_no_difference() {
+        let color = Lab::from_rgb(0x3b, 0x82, 0xf6);
+        assert_eq!(color.delta_e(&color), 0f64);
+    }
+
+    #[test]
+    fn from_rgb_converts_srgb_to_lab() {
+        let close = |color: Lab, (l, a, b): (f64, f64, f64)| {
+            assert!(
+                (color.l - l).abs() < 0.01
+                    && (color.a - a).abs() < 0.01
+                    && (color.b - b).abs() < 0.01,
+                "{:?} != ({}, {}, {})",
+                color,
+                l,
+                a,
+                b
+            );
+        };
+        close(Lab::from_rgb(255, 255, 255), (100.0, 0.0, 0.0));
+        close(Lab::from_rgb(0, 0, 0), (0.0, 0.0, 0.0));
+        close(Lab::from_rgb(255, 0, 0), (53.24, 80.09, 67.20));
+        close(Lab::from_rgb(0, 255, 0), (87.73, -86.18, 83.18));
+        close(Lab::from_rgb(0, 0, 255), (32.30, 79.19, -107.86));
+        // a gray has no chroma
+        close(Lab::from_rgb(119, 119, 119), (50.03, 0.0, 0.0));
+    }
+}
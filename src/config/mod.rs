@@ -1,56 +1,102 @@
-use std::{collections::HashMap, hash::Hash};
-
-use lazy_static::lazy_static;
-
-// pub struct Record = Map<String, String>;
-#[derive(Default)]
-pub type RecordToken = HashMap<&str, &str>;
-pub type ArrayRecordToken = HashMap<&str, Vec<&str>>;
-enum MapRecordValue {
-    MapRecordToken(MapRecordToken), 
-    RecordToken(RecordToken), 
-    String(String)
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// `name: value` of a theme key, in the order of the config
+pub type RecordToken = IndexMap<String, String>;
+
+/// `name: value` of a theme key where a value can also be a list, e.g. `fontFamily`
+pub type ArrayRecordToken = IndexMap<String, StringOrList>;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum StringOrList {
+    String(String),
+    List(Vec<String>),
 }
-pub type MapRecordToken = HashMap<&str, MapRecordValue>;
 
-impl RecordToken {
-    pub fn default() -> RecordToken {
-        HashMap::from::<&str, &str>([ ])
-    }
+/// a color, or the shades of a color, `{ "red": { "100": "#f00", "DEFAULT": "#e00" } }`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ColorValue {
+    Color(String),
+    /// the values of a name repeated in color-token.csv, each one is the token
+    Colors(Vec<String>),
+    Shades(IndexMap<String, ColorValue>),
+}
 
-    pub fn default_from_layout()-> RecordToken {
-        HashMap::from::<&str, &str>([
-            ("xs", ""),
-            ("sm", ""),
-            ("md", ""),
-            ("lg", ""),
-            ("xl", ""),
-            ("2xl", ""),
-            ("3xl", ""),
-            ("4xl", ""),
-            ("5xl", ""),
-            ("6xl", ""),
-            ("7xl", ""),
-        ])
+impl ColorValue {
+    /// add the values of `other` to the same name, `false` for the shades
+    pub fn push_colors(&mut self, other: ColorValue) -> bool {
+        let other = match other {
+            ColorValue::Color(color) => vec![color],
+            ColorValue::Colors(colors) => colors,
+            ColorValue::Shades(_) => return false,
+        };
+        match self {
+            ColorValue::Color(color) => {
+                let mut colors = vec![std::mem::take(color)];
+                colors.extend(other);
+                *self = ColorValue::Colors(colors);
+            }
+            ColorValue::Colors(colors) => colors.extend(other),
+            ColorValue::Shades(_) => return false,
+        }
+        true
     }
 }
 
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FontSizeOptions {
+    pub line_height: Option<String>,
+    pub letter_spacing: Option<String>,
+    pub font_weight: Option<String>,
+}
 
-lazy_static! {
-pub static TailwindConfigThemeField : Vec<&str> = vec![ 
-    "width", "height", "maxWidth", "maxHeight", "minWidth", "minHeight", 
-    "inlineSize", "blockSize", "maxInlineSize", "maxBlockSize", "minInlineSize", "minBlockSize", 
-    "colors", "fontFamily", "fontSize", "breakpoints", "verticalBreakpoints", "borderRadius", 
-    "lineHeight", "letterSpacing", "wordSpacing", "boxShadow", "textIndent", "textShadow", 
-    "textStrokeWidth", "blur", "dropShadow", "easing", "lineWidth", "spacing", "duration", 
-    "ringWidth", "preflightBase", "containers",  
-]; 
+/// `"1rem"`, `["1rem", "1.5rem"]` or `["1rem", { "lineHeight": "1.5rem" }]`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FontSizeValue {
+    Size(String),
+    SizeLineHeight(String, String),
+    SizeOptions(String, FontSizeOptions),
 }
 
+impl FontSizeValue {
+    pub fn font_size(&self) -> &str {
+        match self {
+            FontSizeValue::Size(size)
+            | FontSizeValue::SizeLineHeight(size, _)
+            | FontSizeValue::SizeOptions(size, _) => size,
+        }
+    }
+
+    pub fn line_height(&self) -> Option<&str> {
+        match self {
+            FontSizeValue::Size(_) => None,
+            FontSizeValue::SizeLineHeight(_, line_height) => Some(line_height),
+            FontSizeValue::SizeOptions(_, options) => options.line_height.as_deref(),
+        }
+    }
+}
 
+/// `"768px"`, the screen gets the `at-` / `gt-` / `lt-` variants, or an explicit range
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ScreenValue {
+    Width(String),
+    Range {
+        min: Option<String>,
+        max: Option<String>,
+    },
+}
+
+/// the `theme` of a windi / uno / tailwind config, each key is optional.
+/// `theme.extend` holds the keys added to a table instead of replacing it
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct TailwindConfigSet {
-    pub width:  Option<RecordToken>,
+    pub width: Option<RecordToken>,
     pub height: Option<RecordToken>,
     pub max_width: Option<RecordToken>,
     pub max_height: Option<RecordToken>,
@@ -63,27 +109,34 @@ pub struct TailwindConfigSet {
     pub min_inline_size: Option<RecordToken>,
     pub min_block_size: Option<RecordToken>,
     pub border_radius: Option<RecordToken>,
-    pub breakpoints: Option<RecordToken>,
+    /// tailwind name of the border widths, `lineWidth` of unocss
+    pub border_width: Option<RecordToken>,
+    /// tailwind / windi name of the breakpoints
+    pub screens: Option<IndexMap<String, ScreenValue>>,
+    /// unocss name of the breakpoints
+    pub breakpoints: Option<IndexMap<String, ScreenValue>>,
     pub vertical_breakpoints: Option<RecordToken>,
-    pub colors: Option<RecordToken , MapRecordToken>,
-    pub font_family: Option<RecordToken, ArrayRecordToken>,
-    pub font_size: Option<RecordToken, ArrayRecordToken>,
+    pub colors: Option<IndexMap<String, ColorValue>>,
+    pub font_family: Option<ArrayRecordToken>,
+    pub font_size: Option<IndexMap<String, FontSizeValue>>,
     pub line_height: Option<RecordToken>,
     pub letter_spacing: Option<RecordToken>,
     pub word_spacing: Option<RecordToken>,
-    pub box_shadow:Option<RecordToken, ArrayRecordToken>,
+    pub box_shadow: Option<ArrayRecordToken>,
     pub text_indent: Option<RecordToken>,
-    pub text_shadow:Option<RecordToken, ArrayRecordToken>,
+    pub text_shadow: Option<ArrayRecordToken>,
     pub text_stroke_width: Option<RecordToken>,
     pub ring_width: Option<RecordToken>,
     pub line_width: Option<RecordToken>,
     pub spacing: Option<RecordToken>,
     pub duration: Option<RecordToken>,
+    /// tailwind name of `duration`
+    pub transition_duration: Option<RecordToken>,
     pub aria: Option<RecordToken>,
     pub data: Option<RecordToken>,
     // filters
     pub blur: Option<RecordToken>,
-    pub drop_shadow:Option<RecordToken, ArrayRecordToken>,
+    pub drop_shadow: Option<ArrayRecordToken>,
     // transitions
     pub easing: Option<RecordToken>,
     // media queries
@@ -101,73 +154,111 @@ pub struct TailwindConfigSet {
     pub grid_row: Option<RecordToken>,
     pub grid_template_column: Option<RecordToken>,
     pub grid_template_row: Option<RecordToken>,
-    // container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extend: Option<Box<TailwindConfigSet>>,
+    // vars
+    // Used to generate CSS variables placeholder in preflight
+    //   preflightRoot?: Arrayable<string>
+    //   preflightBase?: Record<string, string | number>
+}
 
-  // vars
-  /** Used to generate CSS variables placeholder in preflight */
-//   preflightRoot?: Arrayable<string>
-//   preflightBase?: Record<string, string | number>
+/// call `$merge(&mut self.$field, other.$field)` for each theme key
+macro_rules! for_each_key {
+    ($self:ident, $other:ident, $merge:ident) => {
+        for_each_key!(
+            $self, $other, $merge, width, height, max_width, max_height, min_width, min_height,
+            inline_size, block_size, max_inline_size, max_block_size, min_inline_size,
+            min_block_size, border_radius, border_width, screens, breakpoints, vertical_breakpoints, colors,
+            font_family, font_size, line_height, letter_spacing, word_spacing, box_shadow,
+            text_indent, text_shadow, text_stroke_width, ring_width, line_width, spacing,
+            duration, transition_duration, aria, data, blur, drop_shadow, easing, media, supports, containers,
+            grid_auto_column, grid_auto_row, grid_column, grid_row, grid_template_column,
+            grid_template_row
+        )
+    };
+    ($self:ident, $other:ident, $merge:ident, $($field:ident),+) => {
+        $($merge(&mut $self.$field, $other.$field);)+
+    };
 }
 
+fn replace_key<V>(key: &mut Option<V>, other: Option<V>) {
+    if other.is_some() {
+        *key = other;
+    }
+}
+
+fn extend_key<K: std::hash::Hash + Eq, V>(
+    key: &mut Option<IndexMap<K, V>>,
+    other: Option<IndexMap<K, V>>,
+) {
+    if let Some(other) = other {
+        key.get_or_insert_with(IndexMap::new).extend(other);
+    }
+}
 
 impl TailwindConfigSet {
-    pub fn default() -> TailwindConfigSet{
-        TailwindConfigSet{
-            width: HashMap<String, String>::new(),
-            height: HashMap<String, String>::new(),
-            max_width: HashMap<String, String>::new(),
-            max_height: HashMap<String, String>::new(),
-            min_width: HashMap<String, String>::new(),
-            min_height: HashMap<String, String>::new(),
-            inline_size: HashMap<String, String>::new(),
-            block_size: HashMap<String, String>::new(),
-            max_inline_size: HashMap<String, String>::new(),
-            max_block_size: HashMap<String, String>::new(),
-            min_inline_size: HashMap<String, String>::new(),
-            min_block_size: HashMap<String, String>::new(),
-            border_radius: HashMap<String, String>::new(),
-            breakpoints: HashMap<String, String>::new(),
-            vertical_breakpoints: HashMap<String, String>::new(),
-            colors: HashMap<String, String>::new(),
-            font_family: HashMap<String, String>::new(),
-            font_size: HashMap<String, String>::new(),
-            line_height: HashMap<String, String>::new(),
-            letter_spacing: HashMap<String, String>::new(),
-            word_spacing: HashMap<String, String>::new(),
-            box_shadow: HashMap<String, String>::new(),
-            text_indent: HashMap<String, String>::new(),
-            text_shadow: HashMap<String, String>::new(),
-            text_stroke_width: HashMap<String, String>::new(),
-            ring_width: HashMap<String, String>::new(),
-            line_width: HashMap<String, String>::new(),
-            spacing: HashMap<String, String>::new(),
-            duration: HashMap<String, String>::new(),
-            aria: HashMap<String, String>::new(),
-            data: HashMap<String, String>::new(),
-            blur: HashMap<String, String>::new(),
-            drop_shadow: HashMap<String, String>::new(),
-            easing: HashMap<String, String>::new(),
-            media: HashMap<String, String>::new(),
-            supports: HashMap<String, String>::new(),
-            containers: HashMap<String, String>::new(),
-            // animation: HashMap<String, String>::new(),
-            grid_auto_column: HashMap<String, String>::new(),
-            grid_auto_row: HashMap<String, String>::new(),
-            grid_column: HashMap<String, String>::new(),
-            grid_row: HashMap<String, String>::new(),
-            grid_template_column: HashMap<String, String>::new(),
-            grid_template_row: HashMap<String, String>::new(),
+    pub fn new() -> TailwindConfigSet {
+        TailwindConfigSet::default()
+    }
+
+    /// the `theme` of a config, or the theme object itself
+    pub fn from_json_value(mut value: serde_json::Value) -> serde_json::Result<TailwindConfigSet> {
+        if let Some(theme) = value.get_mut("theme") {
+            value = theme.take();
         }
+        serde_json::from_value(value)
     }
-    pub fn from_json_string() -> TailwindConfigSet {
-        return TailwindConfigSet::default();
-        // TailwindConfigSet{}
+
+    /// the keys of `other` replace the keys of `self`, then the keys of
+    /// `other.extend` are added, the result has no `extend`
+    pub fn merge(&mut self, mut other: TailwindConfigSet) {
+        let other_extend = other.extend.take();
+        if let Some(extend) = self.extend.take() {
+            self.extend_with(*extend);
+        }
+        for_each_key!(self, other, replace_key);
+        if let Some(extend) = other_extend {
+            self.extend_with(*extend);
+        }
     }
-}
 
+    /// add the entries of each key of `other`, an entry of the same name is replaced
+    pub fn extend_with(&mut self, other: TailwindConfigSet) {
+        for_each_key!(self, other, extend_key);
+    }
 
+    /// the size scales, `("maxWidth", tokens)`
+    pub fn size_scales(&self) -> Vec<(&'static str, &RecordToken)> {
+        [
+            ("width", &self.width),
+            ("height", &self.height),
+            ("minWidth", &self.min_width),
+            ("minHeight", &self.min_height),
+            ("maxWidth", &self.max_width),
+            ("maxHeight", &self.max_height),
+            ("inlineSize", &self.inline_size),
+            ("blockSize", &self.block_size),
+            ("minInlineSize", &self.min_inline_size),
+            ("minBlockSize", &self.min_block_size),
+            ("maxInlineSize", &self.max_inline_size),
+            ("maxBlockSize", &self.max_block_size),
+            ("spacing", &self.spacing),
+            ("borderRadius", &self.border_radius),
+            ("borderWidth", &self.border_width),
+            ("lineWidth", &self.line_width),
+            ("lineHeight", &self.line_height),
+            ("letterSpacing", &self.letter_spacing),
+        ]
+        .into_iter()
+        .filter_map(|(scale, tokens)| tokens.as_ref().map(|tokens| (scale, tokens)))
+        .collect()
+    }
+}
 
+/// a whole config file, only the `theme` is read
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TailwindRawConfigSet {
     pub theme: TailwindConfigSet,
     // pub rules: Vec<>
-}
\ No newline at end of file
+}
@@ -0,0 +1,239 @@
+use crate::tailwind_token::{TailwindTokenSet, UnresolvedKind};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+fn percentage(converted: usize, dropped: usize) -> f32 {
+    let total = converted + dropped;
+    if total == 0 {
+        return 100f32;
+    }
+    ((converted as f32 / total as f32) * 10000f32).round() / 100f32
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PropertyCoverage {
+    pub converted: usize,
+    pub dropped: usize,
+    pub percentage: f32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RuleCoverage {
+    pub selector: String,
+    pub media_query: Vec<String>,
+    /// `property: value` of the declarations that produced tokens
+    pub converted: Vec<String>,
+    /// `property: value` of the declarations without any token
+    pub dropped: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FileCoverage {
+    pub file: String,
+    pub converted: usize,
+    pub dropped: usize,
+    pub percentage: f32,
+    pub unmatched_colors: usize,
+    pub unresolved_media_queries: usize,
+    pub unresolved_variables: usize,
+    pub rules: Vec<RuleCoverage>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FailedFile {
+    pub file: String,
+    pub message: String,
+}
+
+/// how many declarations of the converted stylesheets are turned into tokens
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CoverageReport {
+    pub converted: usize,
+    pub dropped: usize,
+    pub percentage: f32,
+    /// colors without a token in the color preset
+    pub unmatched_colors: usize,
+    /// media queries without a breakpoint token
+    pub unresolved_media_queries: usize,
+    /// `var()` without a declaration or a fallback
+    pub unresolved_variables: usize,
+    pub files: Vec<FileCoverage>,
+    /// files that could not be converted at all
+    pub failed_files: Vec<FailedFile>,
+    /// aggregate of each css property name over all files
+    pub properties: BTreeMap<String, PropertyCoverage>,
+}
+
+impl CoverageReport {
+    pub fn new() -> CoverageReport {
+        CoverageReport::default()
+    }
+
+    pub fn add_file(&mut self, file: &str, resolved_token: &[TailwindTokenSet]) {
+        let mut file_coverage = FileCoverage {
+            file: file.to_owned(),
+            ..FileCoverage::default()
+        };
+
+        for tw_set in resolved_token {
+            let mut rule = RuleCoverage {
+                selector: tw_set.involved_classnames.join(", "),
+                media_query: tw_set.media_query.to_owned(),
+                ..RuleCoverage::default()
+            };
+            for declaration in &tw_set.declarations {
+                let declaration_str = format!("{}: {}", declaration.property, declaration.value);
+                let property = self
+                    .properties
+                    .entry(declaration.property.to_owned())
+                    .or_default();
+                if declaration.is_converted() {
+                    property.converted += 1;
+                    rule.converted.push(declaration_str);
+                } else {
+                    property.dropped += 1;
+                    rule.dropped.push(declaration_str);
+                }
+            }
+            file_coverage.unmatched_colors += tw_set.unresolved_count(UnresolvedKind::Color);
+            file_coverage.unresolved_media_queries +=
+                tw_set.unresolved_count(UnresolvedKind::MediaQuery);
+            file_coverage.unresolved_variables +=
+                tw_set.unresolved_count(UnresolvedKind::Variable);
+            file_coverage.converted += rule.converted.len();
+            file_coverage.dropped += rule.dropped.len();
+            file_coverage.rules.push(rule);
+        }
+        file_coverage.percentage = percentage(file_coverage.converted, file_coverage.dropped);
+
+        self.converted += file_coverage.converted;
+        self.dropped += file_coverage.dropped;
+        self.unmatched_colors += file_coverage.unmatched_colors;
+        self.unresolved_media_queries += file_coverage.unresolved_media_queries;
+        self.unresolved_variables += file_coverage.unresolved_variables;
+        self.percentage = percentage(self.converted, self.dropped);
+        for property in self.properties.values_mut() {
+            property.percentage = percentage(property.converted, property.dropped);
+        }
+        self.files.push(file_coverage);
+    }
+
+    pub fn add_failed_file(&mut self, file: &str, message: &str) {
+        self.failed_files.push(FailedFile {
+            file: file.to_owned(),
+            message: message.to_owned(),
+        });
+    }
+
+    /// dropped declarations, unmatched colors, unresolved media queries and variables
+    pub fn unresolved_count(&self) -> usize {
+        self.dropped
+            + self.unmatched_colors
+            + self.unresolved_media_queries
+            + self.unresolved_variables
+    }
+
+    /// `unresolved_count` over the declaration count, in percent
+    pub fn unresolved_percentage(&self) -> f32 {
+        let total = self.converted + self.dropped;
+        if total == 0 {
+            return 0f32;
+        }
+        ((self.unresolved_count() as f32 / total as f32) * 10000f32).round() / 100f32
+    }
+
+    /// plain text table of the report
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        text += "file coverage\n";
+        for file in &self.files {
+            text += &format!(
+                "  {:>6.2}%  {:>5} / {:<5}  {}\n",
+                file.percentage,
+                file.converted,
+                file.converted + file.dropped,
+                file.file
+            );
+            for rule in file.rules.iter().filter(|r| !r.dropped.is_empty()) {
+                let mut selector = rule.selector.to_owned();
+                if !rule.media_query.is_empty() {
+                    selector = format!("{} @media({})", selector, rule.media_query.join(" "));
+                }
+                text += &format!("      {}\n", selector);
+                for declaration in &rule.dropped {
+                    text += &format!("        - {}\n", declaration);
+                }
+            }
+        }
+        text += "property coverage\n";
+        for (name, property) in &self.properties {
+            text += &format!(
+                "  {:>6.2}%  {:>5} / {:<5}  {}\n",
+                property.percentage,
+                property.converted,
+                property.converted + property.dropped,
+                name
+            );
+        }
+        for failed in &self.failed_files {
+            text += &format!("failed  {}: {}\n", failed.file, failed.message);
+        }
+        text += &format!(
+            "total {:.2}% ({} / {} declarations), {} unmatched colors, {} unresolved media queries, {} unresolved variables\n",
+            self.percentage,
+            self.converted,
+            self.converted + self.dropped,
+            self.unmatched_colors,
+            self.unresolved_media_queries,
+            self.unresolved_variables
+        );
+        text
+    }
+}
+
+/// how many unresolved values a run may leave before it fails,
+/// `10` is a count, `5%` is relative to the declaration count
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MaxUnresolved {
+    Count(usize),
+    Percentage(f32),
+}
+
+impl MaxUnresolved {
+    pub fn is_exceeded(&self, report: &CoverageReport) -> bool {
+        match self {
+            MaxUnresolved::Count(count) => report.unresolved_count() > *count,
+            MaxUnresolved::Percentage(percentage) => report.unresolved_percentage() > *percentage,
+        }
+    }
+}
+
+impl FromStr for MaxUnresolved {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.strip_suffix('%') {
+            Some(percentage) => percentage
+                .trim()
+                .parse::<f32>()
+                .ok()
+                .filter(|p| (0f32..=100f32).contains(p))
+                .map(MaxUnresolved::Percentage)
+                .ok_or(format!("`{}` is not a percentage between 0% and 100%", s)),
+            None => s
+                .parse::<usize>()
+                .map(MaxUnresolved::Count)
+                .map_err(|_| format!("`{}` is not a count or a percentage like `5%`", s)),
+        }
+    }
+}
+
+impl fmt::Display for MaxUnresolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MaxUnresolved::Count(count) => write!(f, "{}", count),
+            MaxUnresolved::Percentage(percentage) => write!(f, "{}%", percentage),
+        }
+    }
+}
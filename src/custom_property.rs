@@ -0,0 +1,317 @@
+use crate::tailwind_token::TailwindTokenSet;
+use crate::theme::size_px;
+use indexmap::IndexMap;
+use lightningcss::{
+    properties::{custom::CustomPropertyName, Property},
+    rules::{style::StyleRule, CssRule},
+    stylesheet::PrinterOptions,
+    traits::Parse,
+    values::color::CssColor,
+};
+use serde_json::{json, Map, Value};
+
+/// the custom properties `--name: value` of a stylesheet
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CustomProperties {
+    /// the properties of `:root` / `html`, seen by every rule
+    pub root: IndexMap<String, String>,
+    /// the properties of the other selectors, seen by the rules of the same selector
+    /// and of its descendants, `.card` is seen by `.card .title`
+    pub scoped: IndexMap<String, IndexMap<String, String>>,
+}
+
+pub fn is_root_selector(selector: &str) -> bool {
+    matches!(selector.trim(), ":root" | "html")
+}
+
+/// the custom properties declared by a rule, the later declaration of a name wins
+pub fn declared_custom_properties(rule: &StyleRule) -> IndexMap<String, String> {
+    let mut properties = IndexMap::new();
+    for prop in &rule.declarations.declarations {
+        if let Property::Custom(custom) = prop {
+            if let CustomPropertyName::Custom(name) = &custom.name {
+                let value = prop
+                    .value_to_css_string(PrinterOptions::default())
+                    .unwrap_or_default();
+                properties.insert(name.to_string(), value.trim().to_owned());
+            }
+        }
+    }
+    properties
+}
+
+impl CustomProperties {
+    pub fn new() -> CustomProperties {
+        CustomProperties::default()
+    }
+
+    /// the custom properties of the top level style rules, the properties in a media
+    /// query only apply on some screens and are not collected
+    pub fn collect(rules: &[CssRule]) -> CustomProperties {
+        let mut properties = CustomProperties::new();
+        for rule in rules {
+            let CssRule::Style(style) = rule else {
+                continue;
+            };
+            let declared = declared_custom_properties(style);
+            if declared.is_empty() {
+                continue;
+            }
+            for selector in style.selectors.to_string().split(", ") {
+                if is_root_selector(selector) {
+                    properties.root.extend(declared.clone());
+                } else {
+                    properties
+                        .scoped
+                        .entry(selector.to_owned())
+                        .or_default()
+                        .extend(declared.clone());
+                }
+            }
+        }
+        properties
+    }
+
+    /// the value of `name` seen by a rule of `selectors`, the nearest scope first
+    pub fn lookup(&self, selectors: &[String], name: &str) -> Option<&str> {
+        for selector in selectors {
+            let scope = self
+                .scoped
+                .iter()
+                .filter(|(scope, _)| {
+                    selector == *scope
+                        || selector.starts_with(&format!("{} ", scope))
+                        || selector.starts_with(&format!("{}>", scope))
+                })
+                // the longest selector is the nearest ancestor
+                .max_by_key(|(scope, _)| scope.len());
+            if let Some(value) = scope.and_then(|(_, properties)| properties.get(name)) {
+                return Some(value);
+            }
+        }
+        self.root.get(name).map(|value| value.as_str())
+    }
+
+    /// the value with each `var(--name, fallback)` replaced, `None` when a variable is
+    /// not declared and has no fallback
+    pub fn substitute(&self, selectors: &[String], value: &str) -> Option<String> {
+        self.substitute_names(selectors, value, &mut Vec::new())
+    }
+
+    /// `names` are the variables being substituted, a variable inside its own value,
+    /// `--a: var(--b); --b: var(--a)`, is not declared and takes its fallback
+    fn substitute_names<'a>(
+        &'a self,
+        selectors: &[String],
+        value: &'a str,
+        names: &mut Vec<&'a str>,
+    ) -> Option<String> {
+        let mut result = String::new();
+        let mut rest = value;
+        while let Some(start) = rest.find("var(") {
+            result.push_str(&rest[..start]);
+            let inner_start = start + "var(".len();
+            let inner_len = closing_paren(&rest[inner_start..])?;
+            let inner = &rest[inner_start..inner_start + inner_len];
+            let (name, fallback) = match top_level_comma(inner) {
+                Some(comma) => (inner[..comma].trim(), Some(inner[comma + 1..].trim())),
+                None => (inner.trim(), None),
+            };
+            let replaced = match self.lookup(selectors, name) {
+                Some(declared) if !names.contains(&name) => {
+                    names.push(name);
+                    let replaced = self.substitute_names(selectors, declared, names);
+                    names.pop();
+                    replaced
+                }
+                _ => None,
+            };
+            let replaced = match (replaced, fallback) {
+                (Some(replaced), _) => replaced,
+                (None, Some(fallback)) => self.substitute_names(selectors, fallback, names)?,
+                (None, None) => return None,
+            };
+            result.push_str(&replaced);
+            rest = &rest[inner_start + inner_len + 1..];
+        }
+        result.push_str(rest);
+        Some(result)
+    }
+}
+
+/// the length up to the `)` closing an already opened paren
+fn closing_paren(value: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(i),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn top_level_comma(value: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// add the variables declared by the `:root` / `html` rules of a file
+pub fn extend_root_variables(
+    variables: &mut IndexMap<String, String>,
+    resolved_token: &[TailwindTokenSet],
+) {
+    for tw_set in resolved_token {
+        // a rule in a media query only applies on some screens
+        if tw_set.media_query.is_empty()
+            && tw_set
+                .involved_classnames
+                .iter()
+                .any(|selector| is_root_selector(selector))
+        {
+            variables.extend(tw_set.variables.clone());
+        }
+    }
+}
+
+/// a theme config of the `:root` / `html` variables, a color is added to `colors` and a
+/// px / rem length to `spacing`, named without the `--`. read it back with `-p`
+pub fn variables_theme(variables: &IndexMap<String, String>) -> Value {
+    let root = CustomProperties {
+        root: variables.to_owned(),
+        ..CustomProperties::default()
+    };
+    let mut colors = Map::new();
+    let mut spacing = Map::new();
+    for name in variables.keys() {
+        let Some(value) = root.substitute(&[], &format!("var({})", name)) else {
+            continue;
+        };
+        let token_name = name.trim_start_matches("--").to_owned();
+        if CssColor::parse_string(&value).is_ok() {
+            colors.insert(token_name, Value::String(value));
+        } else if size_px(&value).is_some() {
+            spacing.insert(token_name, Value::String(value));
+        }
+    }
+    let mut extend = Map::new();
+    if !colors.is_empty() {
+        extend.insert("colors".to_owned(), Value::Object(colors));
+    }
+    if !spacing.is_empty() {
+        extend.insert("spacing".to_owned(), Value::Object(spacing));
+    }
+    json!({ "theme": { "extend": extend } })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_to_tw_token;
+    use crate::tailwind_token::{UnresolvedKind, UnresolvedValue};
+    use crate::theme::{BaseTheme, Theme};
+
+    fn root(variables: &[(&str, &str)]) -> CustomProperties {
+        CustomProperties {
+            root: variables
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            ..CustomProperties::default()
+        }
+    }
+
+    #[test]
+    fn declared_variable_and_fallback() {
+        let variables = root(&[("--gap", "1rem")]);
+        assert_eq!(
+            variables.substitute(&[], "var(--gap)").as_deref(),
+            Some("1rem")
+        );
+        assert_eq!(
+            variables
+                .substitute(&[], "var(--gap, 2rem) var(--none, 4px)")
+                .as_deref(),
+            Some("1rem 4px")
+        );
+        assert_eq!(variables.substitute(&[], "var(--none)"), None);
+    }
+
+    #[test]
+    fn nested_var_in_the_fallback() {
+        let variables = root(&[("--gap", "1rem")]);
+        assert_eq!(
+            variables
+                .substitute(&[], "var(--none, var(--gap))")
+                .as_deref(),
+            Some("1rem")
+        );
+        assert_eq!(
+            variables
+                .substitute(&[], "var(--none, var(--other, calc(2px + 1px)))")
+                .as_deref(),
+            Some("calc(2px + 1px)")
+        );
+        assert_eq!(variables.substitute(&[], "var(--none, var(--other))"), None);
+    }
+
+    #[test]
+    fn nearest_scope_first() {
+        let mut variables = root(&[("--gap", "1rem")]);
+        variables.scoped.insert(
+            ".card".to_owned(),
+            IndexMap::from([("--gap".to_owned(), "2rem".to_owned())]),
+        );
+        let selectors = vec![".card .title".to_owned()];
+        assert_eq!(variables.lookup(&selectors, "--gap"), Some("2rem"));
+        assert_eq!(
+            variables.lookup(&[".cards".to_owned()], "--gap"),
+            Some("1rem")
+        );
+    }
+
+    #[test]
+    fn cyclic_variables_take_the_fallback() {
+        let variables = root(&[
+            ("--a", "var(--b)"),
+            ("--b", "var(--a)"),
+            ("--self", "var(--self) var(--self) var(--self) var(--self)"),
+        ]);
+        assert_eq!(variables.substitute(&[], "var(--a)"), None);
+        assert_eq!(variables.substitute(&[], "var(--self)"), None);
+        assert_eq!(
+            variables.substitute(&[], "var(--a, 1rem)").as_deref(),
+            Some("1rem")
+        );
+    }
+
+    #[test]
+    fn undefined_variable_is_unresolved() {
+        let (theme, _) = Theme::from_presets(BaseTheme::Custom, &[]).unwrap();
+        let tw_sets = parse_to_tw_token(
+            &theme,
+            ":root { --a: var(--b); --b: var(--a) } .x { color: var(--none) } .y { margin: var(--a) }",
+            "test.css",
+        )
+        .unwrap();
+        assert_eq!(
+            tw_sets[1].unresolved,
+            vec![UnresolvedValue {
+                kind: UnresolvedKind::Variable,
+                value: "var(--none)".to_owned(),
+            }]
+        );
+        assert_eq!(tw_sets[1].tailwind_token, vec!["text-[var(--none)]"]);
+        assert_eq!(tw_sets[2].unresolved[0].kind, UnresolvedKind::Variable);
+    }
+}
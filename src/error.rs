@@ -0,0 +1,133 @@
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+/// errors of reading the presets and converting a stylesheet,
+/// each one carries the file and, when known, the line and column
+#[derive(Debug)]
+pub enum RewindError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// lightningcss can not parse the stylesheet
+    Css {
+        path: PathBuf,
+        line: u32,
+        column: u32,
+        message: String,
+    },
+    /// grass can not compile the scss, the message has the location
+    Scss {
+        path: PathBuf,
+        message: String,
+    },
+    /// a preset file, or a row of a preset csv
+    Preset {
+        path: PathBuf,
+        line: u64,
+        message: String,
+    },
+    Glob(globset::Error),
+}
+
+pub type Result<T> = std::result::Result<T, RewindError>;
+
+impl RewindError {
+    pub fn io(path: &Path, source: io::Error) -> RewindError {
+        RewindError::Io {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+
+    /// from the lightningcss error of parsing `file_context`, `path` is the
+    /// `ParserOptions::filename`
+    pub fn css<T: fmt::Display>(path: &str, err: lightningcss::error::Error<T>) -> RewindError {
+        let (line, column) = match &err.loc {
+            // lightningcss counts the lines from 0
+            Some(loc) => (loc.line + 1, loc.column),
+            None => (0, 0),
+        };
+        RewindError::Css {
+            path: PathBuf::from(path),
+            line,
+            column,
+            message: err.kind.to_string(),
+        }
+    }
+
+    pub fn scss(path: &Path, err: Box<grass::Error>) -> RewindError {
+        RewindError::Scss {
+            path: path.to_path_buf(),
+            message: err.to_string(),
+        }
+    }
+
+    pub fn preset(path: &Path, line: u64, message: impl Into<String>) -> RewindError {
+        RewindError::Preset {
+            path: path.to_path_buf(),
+            line,
+            message: message.into(),
+        }
+    }
+
+    /// replace the file name the error was created with, e.g. the layer name
+    /// passed to `parse_to_tw_token` by the path of the input file
+    pub fn with_path(mut self, new_path: &Path) -> RewindError {
+        match &mut self {
+            RewindError::Io { path, .. }
+            | RewindError::Css { path, .. }
+            | RewindError::Scss { path, .. }
+            | RewindError::Preset { path, .. } => *path = new_path.to_path_buf(),
+            RewindError::Glob(_) => {}
+        }
+        self
+    }
+}
+
+impl fmt::Display for RewindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RewindError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            RewindError::Css {
+                path,
+                line,
+                column,
+                message,
+            } => write!(f, "{}:{}:{}: {}", path.display(), line, column, message),
+            RewindError::Scss { path, message } => write!(f, "{}: {}", path.display(), message),
+            // line 0 is an error of the whole file
+            RewindError::Preset {
+                path,
+                line: 0,
+                message,
+            } => {
+                write!(f, "{}: {}", path.display(), message)
+            }
+            RewindError::Preset {
+                path,
+                line,
+                message,
+            } => write!(f, "{}:{}: {}", path.display(), line, message),
+            RewindError::Glob(err) => write!(f, "invalid glob pattern: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for RewindError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RewindError::Io { source, .. } => Some(source),
+            RewindError::Glob(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<globset::Error> for RewindError {
+    fn from(err: globset::Error) -> RewindError {
+        RewindError::Glob(err)
+    }
+}
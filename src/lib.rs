@@ -1,4 +1,14 @@
+pub mod color_distance;
+pub mod config;
 pub mod convert_token;
+pub mod coverage;
+pub mod custom_property;
+pub mod error;
+pub mod mobile_first;
 pub mod parse;
 pub mod resolve_token;
+pub mod source_file;
 pub mod tailwind_token;
+pub mod theme;
+pub mod theme_config;
+pub mod theme_js;
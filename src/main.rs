@@ -1,57 +1,517 @@
+pub mod color_distance;
+pub mod config;
 pub mod convert_token;
+pub mod coverage;
+pub mod custom_property;
+pub mod error;
+pub mod mobile_first;
 pub mod parse;
 pub mod resolve_token;
+pub mod source_file;
 pub mod tailwind_token;
+pub mod theme;
+pub mod theme_config;
+pub mod theme_js;
+
+mod cli;
+mod watch;
 
 // use serde_json;
-use crate::parse::parse_to_tw_token;
-// use rayon::prelude::*;
+use crate::cli::{Cli, Command, ConvertArgs, OutputFormat, StdinSyntax};
+use crate::coverage::CoverageReport;
+use crate::custom_property::{extend_root_variables, variables_theme};
+use crate::error::RewindError;
+use crate::mobile_first::to_mobile_first;
+use crate::parse::{
+    is_scss_file, is_scss_partial, parse_scss_to_css, parse_to_tw_token, ScssOptions,
+};
+use crate::source_file::{collect_source_files, SourceFile};
+use crate::tailwind_token::TailwindTokenSet;
+use crate::theme::{BaseTheme, Theme};
+use clap::Parser;
+use indexmap::IndexMap;
+use rayon::prelude::*;
 // use serde_json::{Result, Value};
 use std::{
-    // collections::HashMap,
+    collections::HashMap,
     fs,
-    path::Path,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
 };
 
-fn main() {
-    tailwind_token::init();
-
-    let income_src_dir = Path::new("./input-src/");
-    let outcome_src_dir = Path::new("./out-tw-token/");
-
-    if outcome_src_dir.exists() == false || outcome_src_dir.is_dir() == false {
-        fs::create_dir(outcome_src_dir).ok();
-    }
-
-    // for entry in income_src_dir.read_dir().expect("read_dir call failed") {
-    let file_list: _ = income_src_dir
-        .read_dir()
-        .unwrap()
-        .filter_map(|e| e.ok())
-        .map(|e| e.path())
-        .collect::<Vec<_>>();
-
-    file_list.into_iter().for_each(|entry| {
-        let file_name = entry.file_name().unwrap_or_default();
-        let file_context = fs::read_to_string(&entry).unwrap();
-
-        if entry.extension().unwrap() == "scss" || entry.extension().unwrap() == "scss" {
-            
-        }
-        let resolved_token = parse_to_tw_token(&file_context, &file_name.to_str().unwrap());
-        let outpath = outcome_src_dir
-            .join("s")
-            .with_file_name(file_name)
-            .with_extension("json");
-        println!("outpath: {}", outpath.as_path().display().to_string());
-        fs::write(
-            outpath.as_path(),
-            serde_json::to_string_pretty(&resolved_token).unwrap_or_default(),
-        )
-        .ok();
-        // }
-    });
-    // let mut income_src = Path::new("./input-src/cdt-grid-card.css");
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let verbosity = cli.verbosity();
+
+    match cli.command {
+        Command::Convert(args) => run_convert(&args, verbosity),
+        Command::Preset(args) => {
+            let Some(theme) = load_theme(args.base_theme, &args.preset, verbosity) else {
+                return ExitCode::FAILURE;
+            };
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&theme).unwrap_or_default()
+            );
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+/// build the theme of the presets and print the skipped rows, `None` when a preset file
+/// can not be read
+fn load_theme(base: BaseTheme, presets: &[PathBuf], verbosity: i8) -> Option<Theme> {
+    match Theme::from_presets(base, presets) {
+        Ok((theme, warnings)) => {
+            if verbosity >= 0 {
+                for warning in warnings {
+                    eprintln!("skip preset entry {}", warning);
+                }
+            }
+            Some(theme)
+        }
+        Err(err) => {
+            eprintln!("cannot load preset: {}", err);
+            None
+        }
+    }
+}
+
+fn run_convert(args: &ConvertArgs, verbosity: i8) -> ExitCode {
+    let Some(mut theme) = load_theme(args.base_theme, &args.preset, verbosity) else {
+        return ExitCode::FAILURE;
+    };
+    theme.matching = args.match_options();
+    theme.dialect = args.dialect;
+
+    if args.is_stdin() {
+        let run = ConvertRun {
+            args,
+            theme: &theme,
+            scss_options: args.scss_options(),
+            output: match &args.output {
+                Some(path) if path != Path::new("-") => OutputTarget::File(path.to_owned()),
+                _ => OutputTarget::Stdout,
+            },
+            verbosity,
+        };
+        let layer = args.layer.as_deref().unwrap_or("stdin");
+        let mut report = CoverageReport::new();
+        let mut root_variables = IndexMap::new();
+        match run.convert_stdin() {
+            Ok(resolved_token) => {
+                report.add_file(layer, &resolved_token);
+                extend_root_variables(&mut root_variables, &resolved_token);
+            }
+            Err(err) => report.add_failed_file(layer, &err.to_string()),
+        }
+        let reports_written = write_reports(args, &report, &root_variables);
+        let exit_code = check_unresolved(args, &report, verbosity);
+        if !reports_written {
+            return ExitCode::FAILURE;
+        }
+        return exit_code;
+    }
+
+    let file_list = match collect_source_files(&args.inputs, &args.include, &args.exclude) {
+        Ok(file_list) => file_list,
+        Err(err) => {
+            eprintln!("{}", RewindError::from(err));
+            return ExitCode::FAILURE;
+        }
+    };
+    let file_list: Vec<SourceFile> = file_list
+        .into_iter()
+        .filter(|entry| {
+            let is_partial = is_scss_partial(&entry.path);
+            if is_partial && verbosity >= 2 {
+                eprintln!("skip partial: {}", entry.path.display());
+            }
+            !is_partial
+        })
+        .collect();
+
+    let output = match &args.output {
+        Some(path) if path == Path::new("-") => OutputTarget::Stdout,
+        // a single input file can be written straight to `--output some.json`
+        Some(path)
+            if file_list.len() == 1
+                && args.inputs.iter().all(|p| !p.is_dir())
+                && path.extension().is_some() =>
+        {
+            OutputTarget::File(path.to_owned())
+        }
+        Some(path) => OutputTarget::Directory(path.to_owned()),
+        None => OutputTarget::Directory(PathBuf::from("./out-tw-token")),
+    };
+
+    if matches!(output, OutputTarget::Stdout)
+        && file_list.len() > 1
+        && args.format != OutputFormat::Text
+    {
+        // one json document after another is not json
+        eprintln!(
+            "`-o -` writes a single json document, {} files can not be written to stdout, use an output directory or `--format text`",
+            file_list.len()
+        );
+        return ExitCode::FAILURE;
+    }
+
+    if let OutputTarget::Directory(dir) = &output {
+        // the same relative path of two inputs, or `a.css` and `a.scss`, would overwrite
+        // one output with the other
+        let mut output_files: HashMap<PathBuf, &SourceFile> = HashMap::new();
+        for entry in &file_list {
+            let outpath = entry.output_path(dir, args.format.extension());
+            if let Some(first) = output_files.insert(outpath.to_owned(), entry) {
+                eprintln!(
+                    "{} and {} are both written to {}",
+                    first.path.display(),
+                    entry.path.display(),
+                    outpath.display()
+                );
+                return ExitCode::FAILURE;
+            }
+        }
+        if !dir.exists() || !dir.is_dir() {
+            if let Err(err) = fs::create_dir_all(dir) {
+                eprintln!("cannot create output directory {}", RewindError::io(dir, err));
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let run = ConvertRun {
+        args,
+        theme: &theme,
+        scss_options: args.scss_options(),
+        output,
+        verbosity,
+    };
+
+    if let Some(jobs) = args.jobs {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .build_global()
+            .ok();
+    }
+
+    let started = Instant::now();
+    // convert in parallel, then write and report in the order of `file_list`
+    let results: Vec<Result<Vec<TailwindTokenSet>, RewindError>> = file_list
+        .par_iter()
+        .map(|entry| run.convert_entry(entry))
+        .collect();
+
+    let mut last_result: HashMap<PathBuf, Vec<TailwindTokenSet>> = HashMap::new();
+    let mut report = CoverageReport::new();
+    let mut root_variables = IndexMap::new();
+    for (entry, result) in file_list.iter().zip(results) {
+        let file = entry.relative_path.to_string_lossy();
+        // a file that can not be written is failed as well
+        let result = result.and_then(|resolved_token| {
+            run.write_entry(entry, &resolved_token)?;
+            Ok(resolved_token)
+        });
+        match result {
+            Ok(resolved_token) => {
+                report.add_file(&file, &resolved_token);
+                extend_root_variables(&mut root_variables, &resolved_token);
+                last_result.insert(entry.path.to_owned(), resolved_token);
+            }
+            Err(err) => {
+                if verbosity >= 0 {
+                    eprintln!("skip {}", err);
+                }
+                report.add_failed_file(&file, &err.to_string());
+            }
+        }
+    }
+    if verbosity >= 0 {
+        print_summary(&report, started.elapsed());
+    }
+    let reports_written = write_reports(args, &report, &root_variables);
+    let mut exit_code = check_unresolved(args, &report, verbosity);
+    if !reports_written {
+        exit_code = ExitCode::FAILURE;
+    }
+
+    if args.watch {
+        watch::watch_and_convert(&run, last_result);
+    }
+    exit_code
+}
+
+fn print_summary(report: &CoverageReport, elapsed: Duration) {
+    let rule_count: usize = report.files.iter().map(|f| f.rules.len()).sum();
+    eprintln!(
+        "converted {} files ({} failed) in {:.2?}: {} rules, {} / {} declarations ({}%)",
+        report.files.len(),
+        report.failed_files.len(),
+        elapsed,
+        rule_count,
+        report.converted,
+        report.converted + report.dropped,
+        report.percentage
+    );
+}
+
+/// `--strict` / `--max-unresolved`, fail when a file can not be converted
+/// or too many values are left unresolved
+fn check_unresolved(args: &ConvertArgs, report: &CoverageReport, verbosity: i8) -> ExitCode {
+    let Some(max_unresolved) = args.max_unresolved() else {
+        return ExitCode::SUCCESS;
+    };
+    let mut passed = true;
+    if !report.failed_files.is_empty() {
+        if verbosity >= 0 {
+            eprintln!("{} file(s) failed to convert", report.failed_files.len());
+        }
+        passed = false;
+    }
+    if max_unresolved.is_exceeded(report) {
+        if verbosity >= 0 {
+            eprintln!(
+                "{} unresolved ({}%): {} dropped declarations, {} unmatched colors, {} unresolved media queries, {} unresolved variables, exceeds --max-unresolved {}",
+                report.unresolved_count(),
+                report.unresolved_percentage(),
+                report.dropped,
+                report.unmatched_colors,
+                report.unresolved_media_queries,
+                report.unresolved_variables,
+                max_unresolved
+            );
+        }
+        passed = false;
+    }
+    if passed {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// `--coverage` and `--vars-theme`, `false` when one of them can not be written, the
+/// error is already printed
+fn write_reports(
+    args: &ConvertArgs,
+    report: &CoverageReport,
+    root_variables: &IndexMap<String, String>,
+) -> bool {
+    let mut written = true;
+    if let Err(err) = write_coverage(args, report) {
+        eprintln!("cannot write coverage report {}", err);
+        written = false;
+    }
+    if let Err(err) = write_vars_theme(args, root_variables) {
+        eprintln!("cannot write variables theme {}", err);
+        written = false;
+    }
+    written
+}
+
+fn write_coverage(args: &ConvertArgs, report: &CoverageReport) -> Result<(), RewindError> {
+    let Some(path) = &args.coverage else {
+        return Ok(());
+    };
+    if path == Path::new("-") {
+        eprint!("{}", report.to_text());
+        return Ok(());
+    }
+    let content = if path.extension().map(|e| e == "json").unwrap_or(false) {
+        serde_json::to_string_pretty(report).unwrap_or_default()
+    } else {
+        report.to_text()
+    };
+    fs::write(path, content).map_err(|err| RewindError::io(path, err))
+}
+
+fn write_vars_theme(
+    args: &ConvertArgs,
+    root_variables: &IndexMap<String, String>,
+) -> Result<(), RewindError> {
+    let Some(path) = &args.vars_theme else {
+        return Ok(());
+    };
+    let content =
+        serde_json::to_string_pretty(&variables_theme(root_variables)).unwrap_or_default();
+    fs::write(path, content).map_err(|err| RewindError::io(path, err))
+}
+
+pub enum OutputTarget {
+    Stdout,
+    File(PathBuf),
+    /// mirror the input tree inside the directory
+    Directory(PathBuf),
+}
+
+/// the settings shared by every file of one `convert` command
+pub struct ConvertRun<'a> {
+    pub args: &'a ConvertArgs,
+    /// loaded once, the watch mode keeps using it
+    pub theme: &'a Theme,
+    pub scss_options: ScssOptions,
+    pub output: OutputTarget,
+    pub verbosity: i8,
+}
+
+impl ConvertRun<'_> {
+    /// convert one file and write its output, the error is already printed
+    pub fn convert_file(&self, entry: &SourceFile) -> Result<Vec<TailwindTokenSet>, RewindError> {
+        let result = self.convert_entry(entry).and_then(|resolved_token| {
+            self.write_entry(entry, &resolved_token)?;
+            Ok(resolved_token)
+        });
+        match result {
+            Ok(resolved_token) => Ok(resolved_token),
+            Err(err) => {
+                if self.verbosity >= 0 {
+                    eprintln!("skip {}", err);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    /// convert one file without writing or printing anything, safe to run on any thread
+    pub fn convert_entry(&self, entry: &SourceFile) -> Result<Vec<TailwindTokenSet>, RewindError> {
+        let file_context =
+            fs::read_to_string(&entry.path).map_err(|err| RewindError::io(&entry.path, err))?;
+
+        let scss_options = if is_scss_file(&entry.path) {
+            Some(self.scss_options.for_file(&entry.path))
+        } else {
+            None
+        };
+        let file_name = entry.path.file_name().unwrap_or_default().to_string_lossy();
+        let layer = self.args.layer.to_owned().unwrap_or(file_name.to_string());
+        self.convert_context(file_context, scss_options, &layer)
+            .map_err(|err| err.with_path(&entry.path))
+    }
+
+    /// write the tokens of `entry` to the output target
+    pub fn write_entry(
+        &self,
+        entry: &SourceFile,
+        resolved_token: &[TailwindTokenSet],
+    ) -> Result<(), RewindError> {
+        let outpath = match &self.output {
+            OutputTarget::Stdout => None,
+            OutputTarget::File(path) => Some(path.to_owned()),
+            OutputTarget::Directory(dir) => {
+                Some(entry.output_path(dir, self.args.format.extension()))
+            }
+        };
+        self.write_result(outpath.as_deref(), resolved_token)
+    }
+
+    /// convert the stylesheet piped into stdin, the error is already printed
+    pub fn convert_stdin(&self) -> Result<Vec<TailwindTokenSet>, RewindError> {
+        self.try_convert_stdin().map_err(|err| {
+            if self.verbosity >= 0 {
+                eprintln!("skip {}", err);
+            }
+            err
+        })
+    }
+
+    fn try_convert_stdin(&self) -> Result<Vec<TailwindTokenSet>, RewindError> {
+        let mut file_context = String::new();
+        io::stdin()
+            .read_to_string(&mut file_context)
+            .map_err(|err| RewindError::io(Path::new("stdin"), err))?;
+        let scss_options = match self.args.stdin_syntax {
+            StdinSyntax::Css => None,
+            StdinSyntax::Scss => Some(self.scss_options.to_owned()),
+            StdinSyntax::Sass => Some(ScssOptions {
+                indented_syntax: true,
+                ..self.scss_options.to_owned()
+            }),
+        };
+        let layer = self.args.layer.to_owned().unwrap_or("stdin".to_owned());
+        let resolved_token = self.convert_context(file_context, scss_options, &layer)?;
+
+        let outpath = match &self.output {
+            OutputTarget::File(path) => Some(path.to_owned()),
+            _ => None,
+        };
+        self.write_result(outpath.as_deref(), &resolved_token)?;
+        Ok(resolved_token)
+    }
+
+    /// compile the scss (when `scss_options` is given) and convert the css into tokens
+    fn convert_context(
+        &self,
+        mut file_context: String,
+        scss_options: Option<ScssOptions>,
+        layer: &str,
+    ) -> Result<Vec<TailwindTokenSet>, RewindError> {
+        if let Some(scss_options) = scss_options {
+            file_context = parse_scss_to_css(&file_context, Path::new(layer), &scss_options)?;
+        }
+        let resolved_token = parse_to_tw_token(self.theme, &file_context, layer)?;
+        if self.args.mobile_first {
+            return Ok(to_mobile_first(self.theme, resolved_token));
+        }
+        Ok(resolved_token)
+    }
+
+    /// write to `outpath`, or to stdout when it is `None`
+    fn write_result(
+        &self,
+        outpath: Option<&Path>,
+        resolved_token: &[TailwindTokenSet],
+    ) -> Result<(), RewindError> {
+        match outpath {
+            Some(outpath) => {
+                if self.verbosity >= 1 {
+                    eprintln!("outpath: {}", outpath.display());
+                }
+                write_output(outpath, resolved_token, self.args.format)
+            }
+            None => {
+                let mut content = format_output(resolved_token, self.args.format);
+                if self.args.format != OutputFormat::Text {
+                    content.push('\n');
+                }
+                io::stdout()
+                    .lock()
+                    .write_all(content.as_bytes())
+                    .map_err(|err| RewindError::io(Path::new("stdout"), err))
+            }
+        }
+    }
+}
+
+fn format_output(resolved_token: &[TailwindTokenSet], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(resolved_token).unwrap_or_default(),
+        OutputFormat::JsonCompact => serde_json::to_string(resolved_token).unwrap_or_default(),
+        OutputFormat::Text => resolved_token
+            .iter()
+            .map(|tw_set| {
+                format!(
+                    "{} {{ {} }}\n",
+                    tw_set.involved_classnames.join(", "),
+                    tw_set.class_string
+                )
+            })
+            .collect(),
+    }
+}
+
+fn write_output(
+    outpath: &Path,
+    resolved_token: &[TailwindTokenSet],
+    format: OutputFormat,
+) -> Result<(), RewindError> {
+    if let Some(parent) = outpath.parent() {
+        fs::create_dir_all(parent).map_err(|err| RewindError::io(parent, err))?;
+    }
+    fs::write(outpath, format_output(resolved_token, format))
+        .map_err(|err| RewindError::io(outpath, err))
 }
 
 // fn main() {
@@ -0,0 +1,254 @@
+use crate::tailwind_token::TailwindTokenSet;
+use crate::theme::{media_width_px, Theme};
+
+/// a rule of a single max-width breakpoint variant, `lt-md:` / `max-md:`
+struct MaxWidthRule {
+    /// the index of the rule in the file
+    index: usize,
+    /// the width the rule ends below, in px
+    edge: f32,
+    /// the breakpoint of the widths from the edge up, `md`
+    min_variant: String,
+    /// the variant the tokens are prefixed with, `lt-md:`
+    prefix: String,
+}
+
+/// the max-width variant of a rule, the rule is left as it is when it has other variants.
+/// an arbitrary `max-[600px]:` is left too, only a breakpoint and its `lt-` variant are
+/// known to meet without a gap or an overlap
+fn max_width_rule(theme: &Theme, index: usize, tw_set: &TailwindTokenSet) -> Option<MaxWidthRule> {
+    let [prefix] = tw_set.media_query_prefix.as_slice() else {
+        return None;
+    };
+    let variant = prefix.strip_suffix(':')?;
+    if variant.contains(':') || variant.contains('[') {
+        return None;
+    }
+    let name = variant
+        .strip_prefix("lt-")
+        .or_else(|| variant.strip_prefix("max-"))?;
+    // the max width of `lt-md`, and `md` itself is a breakpoint of the theme
+    let edge = theme
+        .media
+        .iter()
+        .find(|media_set| media_set.token_name == format!("lt-{}", name))
+        .and_then(|media_set| media_set.max_width.as_ref())
+        .and_then(media_width_px)?;
+    theme
+        .media
+        .iter()
+        .find(|media_set| media_set.token_name == name)?;
+    Some(MaxWidthRule {
+        index,
+        edge,
+        min_variant: name.to_owned(),
+        prefix: prefix.to_owned(),
+    })
+}
+
+fn is_base_rule(tw_set: &TailwindTokenSet) -> bool {
+    tw_set.media_query.is_empty() && tw_set.media_query_prefix.is_empty()
+}
+
+/// rewrite the desktop-first rules of each selector, a rule without a variant and the
+/// `lt-md:` / `max-md:` rules of the same selector, into mobile-first tokens: the value
+/// of the narrowest screens without a variant, then `md:` / `lg:` where it changes. a
+/// property the rule without a variant does not declare, or declares without a token,
+/// keeps its max-width variant, its value on the widest screens is not known
+pub fn to_mobile_first(theme: &Theme, mut tw_sets: Vec<TailwindTokenSet>) -> Vec<TailwindTokenSet> {
+    let mut absorbed = vec![false; tw_sets.len()];
+    let mut rewritten = vec![false; tw_sets.len()];
+    for base_index in 0..tw_sets.len() {
+        if !is_base_rule(&tw_sets[base_index]) {
+            continue;
+        }
+        let selector = &tw_sets[base_index].involved_classnames;
+        // a selector with two rules without a variant is left as it is
+        if tw_sets.iter().enumerate().any(|(i, other)| {
+            i != base_index && is_base_rule(other) && &other.involved_classnames == selector
+        }) {
+            continue;
+        }
+        let max_rules: Vec<MaxWidthRule> = tw_sets
+            .iter()
+            .enumerate()
+            .filter(|(_, other)| &other.involved_classnames == selector)
+            .filter_map(|(i, other)| max_width_rule(theme, i, other))
+            .collect();
+        if max_rules.is_empty() {
+            continue;
+        }
+
+        let mut declarations = tw_sets[base_index].declarations.clone();
+        for declaration in declarations.iter_mut() {
+            if declaration.tokens.is_empty() {
+                continue;
+            }
+            // the rules that declare the property, each with its unprefixed tokens
+            let overrides: Vec<(&MaxWidthRule, Vec<String>)> = max_rules
+                .iter()
+                .filter_map(|rule| {
+                    let tokens = tw_sets[rule.index]
+                        .declarations
+                        .iter()
+                        .find(|other| other.property == declaration.property)?
+                        .tokens
+                        .iter()
+                        .map(|token| token.strip_prefix(&rule.prefix).unwrap_or(token).to_owned())
+                        .collect();
+                    Some((rule, tokens))
+                })
+                .collect();
+            if overrides.is_empty() {
+                continue;
+            }
+            let mut edges: Vec<f32> = overrides.iter().map(|(rule, _)| rule.edge).collect();
+            edges.sort_by(|a, b| a.total_cmp(b));
+            edges.dedup();
+
+            // the value of each range of widths, from 0 up to the first edge, then from
+            // each edge up to the next, is the rule that comes last in the file among the
+            // rules that apply to the range
+            let value_from = |start: f32| -> &Vec<String> {
+                overrides
+                    .iter()
+                    .filter(|(rule, _)| rule.edge > start)
+                    .map(|(rule, tokens)| (rule.index, tokens))
+                    .chain([(base_index, &declaration.tokens)])
+                    .max_by_key(|(index, _)| *index)
+                    .map(|(_, tokens)| tokens)
+                    .unwrap_or(&declaration.tokens)
+            };
+            let mut tokens = value_from(0f32).to_owned();
+            let mut previous = tokens.to_owned();
+            for edge in &edges {
+                let value = value_from(*edge);
+                if value != &previous {
+                    let min_variant = overrides
+                        .iter()
+                        .find(|(rule, _)| rule.edge == *edge)
+                        .map(|(rule, _)| rule.min_variant.as_str())
+                        .unwrap_or_default();
+                    tokens.extend(
+                        value
+                            .iter()
+                            .map(|token| format!("{}:{}", min_variant, token)),
+                    );
+                }
+                previous = value.to_owned();
+            }
+            declaration.tokens = tokens;
+
+            for (rule, _) in &overrides {
+                tw_sets[rule.index]
+                    .declarations
+                    .retain(|other| other.property != declaration.property);
+            }
+        }
+        tw_sets[base_index].declarations = declarations;
+        rewritten[base_index] = true;
+
+        for rule in &max_rules {
+            rewritten[rule.index] = true;
+            if tw_sets[rule.index].declarations.is_empty() {
+                absorbed[rule.index] = true;
+                let unresolved = tw_sets[rule.index].unresolved.clone();
+                let approximate_colors = tw_sets[rule.index].approximate_colors.clone();
+                tw_sets[base_index].unresolved.extend(unresolved);
+                tw_sets[base_index]
+                    .approximate_colors
+                    .extend(approximate_colors);
+            }
+        }
+    }
+
+    tw_sets
+        .into_iter()
+        .zip(absorbed.into_iter().zip(rewritten))
+        .filter(|(_, (absorbed, _))| !absorbed)
+        .map(|(mut tw_set, (_, rewritten))| {
+            if rewritten {
+                tw_set.tailwind_token = tw_set
+                    .declarations
+                    .iter()
+                    .flat_map(|declaration| declaration.tokens.clone())
+                    .collect();
+                tw_set.update_class_string();
+            }
+            tw_set
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_to_tw_token;
+    use crate::theme::BaseTheme;
+
+    /// the class strings of the mobile-first rules of `css`, against the custom theme,
+    /// `md` from 42rem and `lg` from 66rem
+    fn convert(css: &str) -> Vec<String> {
+        let (theme, _) = Theme::from_presets(BaseTheme::Custom, &[]).unwrap();
+        let tw_sets = parse_to_tw_token(&theme, css, "test.css").unwrap();
+        to_mobile_first(&theme, tw_sets)
+            .into_iter()
+            .map(|tw_set| tw_set.class_string)
+            .collect()
+    }
+
+    #[test]
+    fn base_rule_before_the_max_width_rule() {
+        assert_eq!(
+            convert(
+                ".nav { display: flex } @media (max-width: 41.9rem) { .nav { display: none } }"
+            ),
+            vec!["hidden md:flex"]
+        );
+    }
+
+    #[test]
+    fn base_rule_after_the_max_width_rule_wins_on_every_width() {
+        assert_eq!(
+            convert(
+                "@media (max-width: 41.9rem) { .nav { display: none } } .nav { display: flex }"
+            ),
+            vec!["flex"]
+        );
+    }
+
+    #[test]
+    fn several_breakpoints() {
+        assert_eq!(
+            convert(
+                ".a { display: flex }
+                @media (max-width: 65.9rem) { .a { display: block } }
+                @media (max-width: 41.9rem) { .a { display: none } }"
+            ),
+            vec!["hidden md:block lg:flex"]
+        );
+    }
+
+    #[test]
+    fn property_only_under_the_max_width_keeps_its_variant() {
+        assert_eq!(
+            convert(
+                ".a { display: flex }
+                @media (max-width: 41.9rem) { .a { display: none; visibility: hidden } }"
+            ),
+            vec!["hidden md:flex", "lt-md:invisible"]
+        );
+    }
+
+    #[test]
+    fn untouched_properties_of_the_base_rule_are_kept() {
+        let class_strings = convert(
+            ".a { display: flex; visibility: hidden }
+            @media (max-width: 41.9rem) { .a { display: none } }",
+        );
+        assert_eq!(class_strings.len(), 1);
+        let mut tokens: Vec<&str> = class_strings[0].split(' ').collect();
+        tokens.sort();
+        assert_eq!(tokens, vec!["hidden", "invisible", "md:flex"]);
+    }
+}
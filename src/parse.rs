@@ -1,6 +1,9 @@
 use crate::convert_token::resolve_style;
+use crate::custom_property::{declared_custom_properties, CustomProperties};
+use crate::error::{Result, RewindError};
 use crate::resolve_token::resolve_media_query_prefix;
-use crate::tailwind_token::TailwindTokenSet;
+use crate::tailwind_token::{TailwindTokenSet, UnresolvedKind};
+use crate::theme::Theme;
 use lightningcss::{
     rules::{style::StyleRule, CssRule},
     stylesheet::{ParserOptions, PrinterOptions, StyleSheet},
@@ -10,47 +13,180 @@ use lightningcss::{
 // use serde_json;
 
 use grass;
+use std::path::{Path, PathBuf};
 
-pub fn parse_scss_to_css(file_context: String) -> String {
-    if file_context.contains(r"(\/deep\/|\:\:v-deep)") {}
-    let y = grass::from_string(file_context, &grass::Options::default());
-    return y.unwrap_or_default();
+/// options passed to grass for each run
+#[derive(Clone, Debug, Default)]
+pub struct ScssOptions {
+    /// directories searched by `@use` / `@import` / `@forward`
+    pub load_paths: Vec<PathBuf>,
+    /// `.sass` indented syntax instead of scss
+    pub indented_syntax: bool,
+    /// silence `@warn` and `@debug`
+    pub quiet: bool,
 }
-pub fn parse_to_tw_token(file_context: &str, layer: &str) -> Vec<TailwindTokenSet> {
+
+impl ScssOptions {
+    /// options for compiling the file at `path`, the file's directory is searched first
+    /// so the relative partials of the file are resolved
+    pub fn for_file(&self, path: &Path) -> ScssOptions {
+        let mut options = self.clone();
+        if let Some(parent) = path.parent() {
+            options.load_paths.insert(0, parent.to_path_buf());
+        }
+        options.indented_syntax = path
+            .extension()
+            .map(|e| e.eq_ignore_ascii_case("sass"))
+            .unwrap_or(false);
+        options
+    }
+
+    fn to_grass_options(&self) -> grass::Options<'_> {
+        let syntax = if self.indented_syntax {
+            grass::InputSyntax::Sass
+        } else {
+            grass::InputSyntax::Scss
+        };
+        grass::Options::default()
+            .load_paths(&self.load_paths)
+            .input_syntax(syntax)
+            .quiet(self.quiet)
+    }
+}
+
+/// the vue scoped style pseudo classes that pierce the scope of a component
+const DEEP_PSEUDOS: [&str; 2] = ["::v-deep", ":deep"];
+
+/// `.a :deep(.b)` / `.a::v-deep(.b)` / `.a ::v-deep .b` of a vue scoped style as the plain
+/// descendant `.a .b`. only the selector is rewritten, the strings and urls of the
+/// declarations are not touched
+fn strip_deep_selector(selector: &str) -> String {
+    let mut stripped = String::new();
+    let mut rest = selector;
+    while let Some((start, pseudo)) = DEEP_PSEUDOS
+        .iter()
+        .filter_map(|pseudo| rest.find(pseudo).map(|start| (start, *pseudo)))
+        .min_by_key(|(start, _)| *start)
+    {
+        stripped.push_str(&rest[..start]);
+        stripped.push(' ');
+        rest = &rest[start + pseudo.len()..];
+        if let Some(argument) = rest.strip_prefix('(') {
+            // the argument can have parentheses of its own, `:deep(.b:not(.c))`
+            let mut depth = 1;
+            let end = argument
+                .char_indices()
+                .find(|(_, c)| {
+                    match c {
+                        '(' => depth += 1,
+                        ')' => depth -= 1,
+                        _ => (),
+                    }
+                    depth == 0
+                })
+                .map(|(end, _)| end)
+                .unwrap_or(argument.len());
+            stripped.push_str(&argument[..end]);
+            rest = argument.get(end + 1..).unwrap_or_default();
+        }
+    }
+    stripped.push_str(rest);
+    stripped.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// compile the scss, `path` is only used for the error
+pub fn parse_scss_to_css(file_context: &str, path: &Path, options: &ScssOptions) -> Result<String> {
+    grass::from_string(file_context, &options.to_grass_options())
+    .map_err(|err| RewindError::scss(path, err))
+}
+
+pub fn is_scss_file(path: &Path) -> bool {
+    path.extension()
+        .map(|e| e.eq_ignore_ascii_case("scss") || e.eq_ignore_ascii_case("sass"))
+        .unwrap_or(false)
+}
+
+/// `_name.scss` partials are only compiled through the file that `@use` them
+pub fn is_scss_partial(path: &Path) -> bool {
+    is_scss_file(path)
+        && path
+            .file_name()
+            .map(|f| f.to_string_lossy().starts_with('_'))
+            .unwrap_or(false)
+}
+
+/// the parse error has `layer` as the file name, see `RewindError::with_path`
+pub fn parse_to_tw_token(
+    theme: &Theme,
+    file_context: &str,
+    layer: &str,
+) -> Result<Vec<TailwindTokenSet>> {
     // let fs::read(file_path).unwrap();
     let mut tw_vec: Vec<TailwindTokenSet> = Vec::new();
-    let parser_set = StyleSheet::parse(&file_context, ParserOptions::default()).unwrap();
+    let parser_options = ParserOptions {
+        filename: layer.to_owned(),
+        ..ParserOptions::default()
+    };
+    let parser_set = StyleSheet::parse(file_context, parser_options)
+        .map_err(|err| RewindError::css(layer, err))?;
 
     // println!("{}" , serde_json::to_string_pretty(&parser_set).unwrap());
+    let variables = CustomProperties::collect(&parser_set.rules.0);
 
     for rule in parser_set.rules.0 {
-        let current_rule = rule.to_css_string(PrinterOptions::default()).unwrap();
+        let current_rule = rule
+            .to_css_string(PrinterOptions::default())
+            .unwrap_or_default();
         let current_layer = layer.to_owned();
         match rule {
             CssRule::Media(m) => {
                 // p.query.
                 let mut mq_token: Vec<String> = vec![];
+                let mut mq_unresolved: Vec<String> = vec![];
+                // each query of the list is its own group of variants, `a, b` is `a` or `b`
+                let mut media_prefixes: Vec<String> = vec![];
                 for q in m.query.media_queries {
                     // println!(", {:?}", q);
-                    let ext = resolve_media_query_prefix(q);
+                    let raw_query = q
+                        .to_css_string(PrinterOptions::default())
+                        .unwrap_or_default();
+                    let ext = resolve_media_query_prefix(theme, q);
                     // print!("{:#?} ", ext);
+                    // an arbitrary `[@media(...)]` / `min-[600px]` variant has no breakpoint
+                    if ext.iter().any(|variant| variant.contains('[')) {
+                        mq_unresolved.push(raw_query);
+                    }
+                    media_prefixes.push(ext.iter().map(|t| format!("{}:", t)).collect());
                     mq_token.extend_from_slice(&ext);
                 }
                 let sub_property_count = m.rules.0.len() as i32;
                 for p in m.rules.0 {
                     if let CssRule::Style(s) = p {
-                        let mut tw_set = create_new_tw_token(&s, &current_rule, &current_layer);
+                        let mut tw_set = create_new_tw_token(
+                            theme,
+                            &variables,
+                            &s,
+                            &current_rule,
+                            &current_layer,
+                        );
                         tw_set.set_raw_property_count(sub_property_count);
                         tw_set.push_media_queries(&mq_token);
+                        tw_set.apply_media_prefix(&media_prefixes);
+                        // a query is unresolved once for the block, on its first rule
+                        for raw_query in std::mem::take(&mut mq_unresolved) {
+                            tw_set.push_unresolved(UnresolvedKind::MediaQuery, &raw_query);
+                        }
 
                         // println!("{} part, ", tw_set.involved_classnames.join(" "),);
+                        tw_set.update_class_string();
                         tw_vec.push(tw_set);
                     }
                 }
             }
             CssRule::Style(p) => {
-                let tw_set = create_new_tw_token(&p, &current_rule, &current_layer);
-
+                let mut tw_set =
+                    create_new_tw_token(theme, &variables, &p, &current_rule, &current_layer);
+                tw_set.update_class_string();
                 tw_vec.push(tw_set);
             }
             // CssRule::Import(_) => todo!(),
@@ -76,16 +212,22 @@ pub fn parse_to_tw_token(file_context: &str, layer: &str) -> Vec<TailwindTokenSe
     }
 
     // println!("{}", serde_json::to_string_pretty(&tw_vec).unwrap());
-    tw_vec
+    Ok(tw_vec)
 }
 
-fn create_new_tw_token(p: &StyleRule, current_rule: &str, current_layer: &str) -> TailwindTokenSet {
+fn create_new_tw_token(
+    theme: &Theme,
+    variables: &CustomProperties,
+    p: &StyleRule,
+    current_rule: &str,
+    current_layer: &str,
+) -> TailwindTokenSet {
     let mut tw_set = TailwindTokenSet::new();
     tw_set.push_involved_classnames(
         p.selectors
             .to_string()
             .split(", ")
-            .map(|f| f.to_owned())
+            .map(strip_deep_selector)
             .collect(),
     );
     tw_set.set_layer_group(current_layer);
@@ -93,6 +235,44 @@ fn create_new_tw_token(p: &StyleRule, current_rule: &str, current_layer: &str) -
     // let property_count = p.declarations.declarations.len() as i32;
     tw_set.set_raw_property_count(p.declarations.declarations.len() as i32);
     // let style_rule:Vec<Property> = p.declarations.declarations.iter().collect();
-    resolve_style(p, &mut tw_set);
+    tw_set.variables = declared_custom_properties(p);
+    resolve_style(theme, variables, p, &mut tw_set);
     tw_set
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::BaseTheme;
+
+    fn convert_scss(scss: &str) -> Vec<TailwindTokenSet> {
+        let (theme, _) = Theme::from_presets(BaseTheme::Custom, &[]).unwrap();
+        let css = parse_scss_to_css(scss, Path::new("test.scss"), &ScssOptions::default()).unwrap();
+        parse_to_tw_token(&theme, &css, "test.scss").unwrap()
+    }
+
+    #[test]
+    fn deep_selectors_become_descendants() {
+        assert_eq!(strip_deep_selector(".a :deep(.b)"), ".a .b");
+        assert_eq!(strip_deep_selector(".a::v-deep(.b)"), ".a .b");
+        assert_eq!(strip_deep_selector(".a ::v-deep .b"), ".a .b");
+        assert_eq!(strip_deep_selector(".a :deep(.b:not(.c)) .d"), ".a .b:not(.c) .d");
+        assert_eq!(strip_deep_selector(":deep(.b)"), ".b");
+    }
+
+    #[test]
+    fn deep_selectors_compile_through_grass() {
+        let tw_vec = convert_scss(".a { ::v-deep(.b) { margin: 0; } :deep(.c) { margin: 0; } }");
+        assert_eq!(tw_vec[0].involved_classnames, vec![".a .b"]);
+        assert_eq!(tw_vec[1].involved_classnames, vec![".a .c"]);
+    }
+
+    #[test]
+    fn deep_in_values_is_kept() {
+        let tw_vec = convert_scss(
+            r#".a { background-image: url("img/deep/a.png"); content: ">>> ::v-deep"; }"#,
+        );
+        assert!(tw_vec[0].raw_property.contains(r#"url("img/deep/a.png")"#));
+        assert!(tw_vec[0].raw_property.contains(r#"">>> ::v-deep""#));
+    }
+}
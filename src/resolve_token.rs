@@ -9,6 +9,9 @@ use lightningcss::{
         // Operator
         MediaFeatureValue,
         MediaQuery,
+        MediaType,
+        Operator,
+        Qualifier,
     },
     properties::{
         border::BorderSideWidth,
@@ -30,13 +33,11 @@ use lightningcss::{
 };
 // use parcel_selectors::SelectorList;
 
-use regex::Regex;
-
-use crate::tailwind_token::{
-    search_color, search_font, search_media, search_media_v2, TailwindTokenSet,
-};
+use crate::tailwind_token::{TailwindTokenSet, UnresolvedKind};
+use crate::theme::{media_width_px, media_width_step, Dialect, Theme};
 
 pub fn resolve_track_size(
+    theme: &Theme,
     income_value: &grid::TrackSize,
     tw_set: &mut TailwindTokenSet,
     token_prefix: &str,
@@ -44,7 +45,7 @@ pub fn resolve_track_size(
     match income_value {
         grid::TrackSize::TrackBreadth(k) => match k {
             grid::TrackBreadth::Flex(k) => tw_set.push_tailwind_token(token_prefix, k),
-            grid::TrackBreadth::Length(k) => resolve_dimension(k, tw_set, token_prefix),
+            grid::TrackBreadth::Length(k) => resolve_dimension(theme, k, tw_set, token_prefix),
             grid::TrackBreadth::MinContent => {
                 tw_set.push_tailwind_token(token_prefix, "min");
             }
@@ -66,6 +67,7 @@ pub fn resolve_track_size(
 }
 
 pub fn resolve_length_unit(
+    theme: &Theme,
     income_value: &LengthPercentageOrAuto,
     tw_set: &mut TailwindTokenSet,
     token_prefix: &str,
@@ -75,39 +77,45 @@ pub fn resolve_length_unit(
             tw_set.push_tailwind_token_with_check(token_prefix, "auto");
         }
         LengthPercentageOrAuto::LengthPercentage(a) => {
-            resolve_dimension(a, tw_set, token_prefix);
+            resolve_dimension(theme, a, tw_set, token_prefix);
         }
     }
 }
 
 pub fn resolve_dimension(
+    theme: &Theme,
     length_value: &DimensionPercentage<LengthValue>,
     tw_set: &mut TailwindTokenSet,
     token_prefix: &str,
 ) {
     match length_value {
         LengthPercentage::Dimension(value) => {
-            let mut pxv = match value.to_px() {
-                Some(v) => {
-                    if v >= 24f32 {
-                        format!("{}", (v / 4f32).round())
+            let token = length_px(value).and_then(|px| {
+                theme
+                    .search_size(size_scales(token_prefix), px.abs())
+                    .map(|token| (px, token))
+            });
+            match token {
+                Some((px, token)) => {
+                    let prefix = if px < 0f32 {
+                        format!("-{}", token_prefix)
+                    } else {
+                        token_prefix.to_owned()
+                    };
+                    if token.token_name == "DEFAULT" {
+                        tw_set.push_tailwind_token_with_check("", prefix);
                     } else {
-                        format!("{}", v.round() / 4f32)
+                        tw_set.push_tailwind_token_with_check(&prefix, &token.token_name);
                     }
                 }
-                None => String::from(""),
-            };
-
-            if pxv.eq("") {
-                let (number_value, unit) = value.to_unit_value();
-                if unit.to_lowercase().contains("em") {
-                    pxv = format!("{}", (number_value * 4f32).round());
-                } else {
-                    pxv = format!("{}{}", number_value, unit)
-                }
+                None => tw_set.push_tailwind_token_with_check(
+                    token_prefix,
+                    format!(
+                        "[{}]",
+                        value.to_css_string(PrinterOptions::default()).unwrap()
+                    ),
+                ),
             }
-
-            tw_set.push_tailwind_token_with_check(token_prefix, pxv);
         }
         DimensionPercentage::Percentage(percentage_val) => {
             tw_set.push_tailwind_token_with_check(
@@ -124,13 +132,47 @@ pub fn resolve_dimension(
     };
 }
 
-pub fn resolve_length_size(income_value: &Size, tw_set: &mut TailwindTokenSet, token_prefix: &str) {
+/// a length in px, `em` / `vh` .. depend on the element, only px and rem are on the scale
+fn length_px(value: &LengthValue) -> Option<f32> {
+    match value.to_px() {
+        Some(px) => Some(px),
+        None => match value.to_unit_value() {
+            (rem, "rem") => Some(rem * 16f32),
+            _ => None,
+        },
+    }
+}
+
+/// the theme scales a length of `token_prefix` is looked up in, in order
+fn size_scales(token_prefix: &str) -> &'static [&'static str] {
+    match token_prefix {
+        p if p.starts_with("rounded") => &["borderRadius"],
+        "b" | "b-t" | "b-b" | "b-l" | "b-r" => &["borderWidth", "lineWidth"],
+        // the grid tracks have their own keywords, and no theme has a scale of the
+        // stroke or outline widths, `stroke-2` is 2px, a length is always arbitrary
+        "auto-cols" | "auto-rows" | "stroke" | "outline" => &[],
+        "w" | "inline" => &["width", "spacing"],
+        "h" | "block" => &["height", "spacing"],
+        "min-w" | "min-inline" => &["minWidth", "spacing"],
+        "min-h" | "min-block" => &["minHeight", "spacing"],
+        "max-w" | "max-inline" => &["maxWidth", "spacing"],
+        "max-h" | "max-block" => &["maxHeight", "spacing"],
+        _ => &["spacing"],
+    }
+}
+
+pub fn resolve_length_size(
+    theme: &Theme,
+    income_value: &Size,
+    tw_set: &mut TailwindTokenSet,
+    token_prefix: &str,
+) {
     match income_value {
         Size::Auto => {
             tw_set.push_tailwind_token(token_prefix, "auto");
         }
         Size::LengthPercentage(a) => {
-            resolve_dimension(a, tw_set, token_prefix);
+            resolve_dimension(theme, a, tw_set, token_prefix);
         }
         Size::MinContent(_) => {
             tw_set.push_tailwind_token(token_prefix, "min");
@@ -149,13 +191,14 @@ pub fn resolve_length_size(income_value: &Size, tw_set: &mut TailwindTokenSet, t
     }
 }
 pub fn resolve_length_max_size(
+    theme: &Theme,
     income_value: &MaxSize,
     tw_set: &mut TailwindTokenSet,
     token_prefix: &str,
 ) {
     match income_value {
         MaxSize::LengthPercentage(a) => {
-            resolve_dimension(a, tw_set, token_prefix);
+            resolve_dimension(theme, a, tw_set, token_prefix);
         }
         MaxSize::None => {
             tw_set.push_tailwind_token(token_prefix, "none");
@@ -197,7 +240,10 @@ pub fn resolve_keyword<F: ToCss>(
     );
 }
 
+/// a border / outline width, the step of the width scales of the theme, else the
+/// arbitrary `b-[3px]`. the `DEFAULT` width is the bare `b`
 pub fn resolve_border_side_width(
+    theme: &Theme,
     income_value: &BorderSideWidth,
     tw_set: &mut TailwindTokenSet,
     token_prefix: &str,
@@ -205,27 +251,21 @@ pub fn resolve_border_side_width(
     match income_value {
         BorderSideWidth::Length(p) => match p {
             lightningcss::values::length::Length::Value(value) => {
-                let mut pxv = match value.to_px() {
-                    Some(v) => {
-                        if v >= 24f32 {
-                            format!("{}", (v / 4f32).round())
-                        } else {
-                            format!("{}", v.round() / 4f32)
-                        }
-                    }
-                    None => String::from(""),
-                };
-
-                if pxv.eq("") {
-                    let (number_value, unit) = value.to_unit_value();
-                    if unit.to_lowercase().contains("em") {
-                        pxv = format!("{}", (number_value * 4f32).round());
-                    } else {
-                        pxv = format!("{}{}", number_value, unit)
+                let token =
+                    length_px(value).and_then(|px| theme.search_size(size_scales(token_prefix), px));
+                match token {
+                    Some(token) if token.token_name == "DEFAULT" => {
+                        tw_set.push_tailwind_token("", token_prefix)
                     }
+                    Some(token) => tw_set.push_tailwind_token(token_prefix, &token.token_name),
+                    None => tw_set.push_tailwind_token(
+                        token_prefix,
+                        format!(
+                            "[{}]",
+                            value.to_css_string(PrinterOptions::default()).unwrap()
+                        ),
+                    ),
                 }
-
-                tw_set.push_tailwind_token(token_prefix, pxv);
             }
             lightningcss::values::length::Length::Calc(s) => tw_set.push_tailwind_token(
                 token_prefix,
@@ -236,53 +276,76 @@ pub fn resolve_border_side_width(
     }
 }
 
-pub fn resolve_color(income_value: &CssColor, tw_set: &mut TailwindTokenSet, token_prefix: &str) {
+pub fn resolve_color(
+    theme: &Theme,
+    income_value: &CssColor,
+    tw_set: &mut TailwindTokenSet,
+    token_prefix: &str,
+) {
     if let CssColor::CurrentColor = income_value {
         tw_set.push_tailwind_token(token_prefix, "current");
         return;
     };
 
-    let mut red = 0u8;
-    let mut green = 0u8;
-    let mut blue = 0u8;
-    let mut alpha = 255u8;
-    let mut alpha_float = 1f32;
-    // } ;
-
-    if let Ok(CssColor::RGBA(pp)) = income_value.to_rgb() {
-        red = pp.red;
-        green = pp.green;
-        blue = pp.blue;
-        alpha = pp.alpha;
-        alpha_float = pp.alpha_f32();
+    let resolved_raw = income_value
+        .to_css_string(PrinterOptions::default())
+        .unwrap();
+    // lab, lch, oklab, oklch, color() and color-mix() all convert, a color out of
+    // the srgb gamut is mapped into it
+    let Ok(CssColor::RGBA(rgba)) = income_value.to_rgb() else {
+        resolve_raw_color(&resolved_raw, tw_set, token_prefix);
+        return;
     };
 
-    // println!(
-    //     "[{}] :: r:{}, g:{}, b:{}, a:{}  ",
-    //     token_prefix, red, green, blue, alpha
-    // );
-    if alpha != 255u8 {
-        let op = token_prefix.to_owned() + "-op";
-        tw_set.push_tailwind_token(op.as_str(), (alpha_float * 100f32).round());
-    }
-    let mut resolved_token = search_color(&red, &green, &blue);
-    if resolved_token.len() == 0 {
-        let resolved_raw = income_value
-            .to_css_string(PrinterOptions::default())
-            .unwrap();
-        resolved_token.push(resolved_raw);
-    } else if resolved_token.len() > 1 {
-        let regex_set = Regex::new(r"^(\w+)-(\d+)").unwrap();
-        resolved_token = resolved_token
-            .into_iter()
-            .filter(|token| regex_set.is_match(token) == false)
-            .collect();
+    match theme
+        .search_color(&rgba.red, &rgba.green, &rgba.blue, &rgba.alpha)
+        .first()
+    {
+        Some((token, delta_e)) => {
+            // the distance of the same color is not exactly 0 with the float math
+            let delta_e = (delta_e * 100f32).round() / 100f32;
+            if delta_e > 0f32 {
+                tw_set.push_approximate_color(&resolved_raw, &token.token_name, delta_e);
+            }
+            // an opaque color, or a token of the same alpha like `transparent`
+            if rgba.alpha == token.color_set_alpha {
+                tw_set.push_tailwind_token(token_prefix, &token.token_name);
+                return;
+            }
+            let alpha = rgba.alpha_f32();
+            let percent = (alpha * 100f32).round();
+            match theme.dialect {
+                Dialect::Legacy => {
+                    let opacity = token_prefix.to_owned() + "-opacity";
+                    tw_set.push_tailwind_token(opacity.as_str(), percent);
+                    tw_set.push_tailwind_token(token_prefix, &token.token_name);
+                }
+                // the opacity scale of tailwind has the steps of 5
+                Dialect::Tailwind if percent % 5f32 != 0f32 => {
+                    let alpha = format!("{:.2}", alpha);
+                    tw_set.push_tailwind_token(
+                        token_prefix,
+                        format!("{}/[{}]", token.token_name, alpha.trim_start_matches('0')),
+                    );
+                }
+                _ => tw_set
+                    .push_tailwind_token(token_prefix, format!("{}/{}", token.token_name, percent)),
+            }
+        }
+        None => resolve_raw_color(&resolved_raw, tw_set, token_prefix),
     }
+}
 
-    // println!("resolved-token {}", resolved_token.join(" "));
-    tw_set.push_tailwind_token(token_prefix, &resolved_token[0]);
-
-    // println!()
+/// a color without a token, `text-[oklch(70%_0.1_150)]`. a keyword like a system
+/// color gets the `color:` hint, `text-[color:Canvas]`
+pub fn resolve_raw_color(resolved_raw: &str, tw_set: &mut TailwindTokenSet, token_prefix: &str) {
+    tw_set.push_unresolved(UnresolvedKind::Color, resolved_raw);
+    let value = resolved_raw.split_whitespace().join("_");
+    if value.starts_with('#') || value.contains('(') {
+        tw_set.push_tailwind_token(token_prefix, format!("[{}]", value));
+    } else {
+        tw_set.push_tailwind_token(token_prefix, format!("[color:{}]", value));
+    }
 }
 
 pub fn resolve_number_or_percentage(
@@ -316,6 +379,35 @@ pub fn resolve_time(income_value: &Time, tw_set: &mut TailwindTokenSet, token_pr
     tw_set.push_tailwind_token(token_prefix, time_set);
 }
 
+/// a transition duration, the step of the theme durations, else the arbitrary
+/// `duration-[250ms]`
+pub fn resolve_duration(theme: &Theme, income_value: &Time, tw_set: &mut TailwindTokenSet) {
+    let ms = match *income_value {
+        Time::Seconds(a) => a * 1000f32,
+        Time::Milliseconds(a) => a,
+    };
+    match theme.search_duration(ms) {
+        Some(token_name) => tw_set.push_tailwind_token("duration", token_name),
+        None => tw_set.push_tailwind_token("duration", format!("[{}ms]", ms)),
+    }
+}
+
+/// a box shadow, the step of the theme shadows, the `DEFAULT` is the bare `shadow`, else
+/// the arbitrary `shadow-[0_1px_2px_#000]`
+pub fn resolve_box_shadow<F: ToCss>(theme: &Theme, income_value: &F, tw_set: &mut TailwindTokenSet) {
+    let resolved_raw = income_value
+        .to_css_string(PrinterOptions::default())
+        .unwrap();
+    match theme.search_box_shadow(&resolved_raw) {
+        Some("DEFAULT") => tw_set.push_tailwind_token("", "shadow"),
+        Some(token_name) => tw_set.push_tailwind_token("shadow", token_name),
+        None => tw_set.push_tailwind_token(
+            "shadow",
+            format!("[{}]", resolved_raw.replace(", ", ",").split_whitespace().join("_")),
+        ),
+    }
+}
+
 pub fn resolve_raw_exp<F: ToCss>(
     income_value: &F,
     tw_set: &mut TailwindTokenSet,
@@ -330,19 +422,19 @@ pub fn resolve_raw_exp<F: ToCss>(
     );
 }
 
-pub fn resolve_font_set(income_value: &FontSize, tw_set: &mut TailwindTokenSet) {
+pub fn resolve_font_set(theme: &Theme, income_value: &FontSize, tw_set: &mut TailwindTokenSet) {
     match income_value {
         FontSize::Length(s) => {
             if let DimensionPercentage::Dimension(m) = s {
                 let (value, unit) = m.to_unit_value();
                 if unit.contains("em") {
-                    let tkn = search_font(&value);
+                    let tkn = theme.search_font(&value);
                     for tk in tkn {
                         tw_set.push_tailwind_token("text", tk);
                     }
                 } else if m.to_px().is_some() {
                     let converted = m.to_px().unwrap();
-                    let tkn = search_font(&(converted / 16f32));
+                    let tkn = theme.search_font(&(converted / 16f32));
                     for tk in tkn {
                         tw_set.push_tailwind_token("text", tk);
                     }
@@ -443,23 +535,60 @@ pub fn resolve_line_height_set(
     }
 }
 
-pub fn resolve_media_query_prefix(q: MediaQuery) -> Vec<String> {
+/// the css without spaces as an arbitrary variant, `[@media(hover:hover)]`
+fn arbitrary_media_variant<F: ToCss>(income_value: &F) -> String {
+    let minified = income_value
+        .to_css_string(PrinterOptions {
+            minify: true,
+            ..PrinterOptions::default()
+        })
+        .unwrap_or_default();
+    let media = if minified.starts_with('(') {
+        format!("@media{}", minified)
+    } else {
+        format!("@media {}", minified)
+    };
+    format!("[{}]", media.split_whitespace().join("_"))
+}
+
+/// the variants of a media query, `md`, `print`, `dark`. a media type, a feature or a
+/// condition without a variant becomes an arbitrary variant, `[@media(hover:hover)]`,
+/// `screen` and `all` have none
+pub fn resolve_media_query_prefix(theme: &Theme, q: MediaQuery) -> Vec<String> {
     let mut temp: Vec<String> = vec![];
 
-    match q.condition.unwrap() {
-        MediaCondition::Feature(ss) => resolve_media_query_feat(ss, &mut temp),
+    // `not print and (...)` negates the whole query, it is not a stack of variants
+    if q.qualifier == Some(Qualifier::Not) {
+        temp.push(arbitrary_media_variant(&q));
+        return temp;
+    }
+    match &q.media_type {
+        MediaType::Print => temp.push("print".to_owned()),
+        MediaType::All | MediaType::Screen => (),
+        MediaType::Custom(_) => {
+            temp.push(arbitrary_media_variant(&q));
+            return temp;
+        }
+    }
+    let Some(condition) = q.condition else {
+        return temp;
+    };
+    match condition {
+        MediaCondition::Feature(ss) => resolve_media_query_feat(theme, ss, &mut temp),
         MediaCondition::Operation {
-            operator: _,
+            operator: Operator::And,
             conditions,
         } => {
             for cond in conditions {
                 if let MediaCondition::Feature(fsa) = cond {
-                    resolve_media_query_feat(fsa, &mut temp);
+                    resolve_media_query_feat(theme, fsa, &mut temp);
+                } else {
+                    temp.push(arbitrary_media_variant(&cond));
                 }
             }
         }
-        // MediaCondition::Not(_) => todo!(),
-        _ => (),
+        // `or` and `not` can not be written as stacked variants
+        condition => temp.push(arbitrary_media_variant(&condition)),
     }
     // print!("{:#?} ", temp);
     // return temp;
@@ -492,81 +621,120 @@ pub fn resolve_media_query_prefix(q: MediaQuery) -> Vec<String> {
     return temp;
 }
 
-enum MinifiedMediaFeatureComparison {
-    At,
-    Gt,
-    Lt,
+/// the variant of a preference or orientation feature
+fn media_feature_variant(theme: &Theme, name: &str, value: &str) -> Option<&'static str> {
+    match (name, value) {
+        ("orientation", "portrait") => Some("portrait"),
+        ("orientation", "landscape") => Some("landscape"),
+        ("prefers-reduced-motion", "reduce") => Some("motion-reduce"),
+        ("prefers-reduced-motion", "no-preference") => Some("motion-safe"),
+        ("prefers-contrast", "more") => Some("contrast-more"),
+        ("prefers-contrast", "less") => Some("contrast-less"),
+        ("prefers-color-scheme", "dark") => Some("dark"),
+        // only unocss has a `light` variant
+        ("prefers-color-scheme", "light") if theme.dialect == Dialect::Uno => Some("light"),
+        ("forced-colors", "active") => Some("forced-colors"),
+        _ => None,
+    }
 }
 
-fn resolve_media_query_feat(ss: MediaFeature, temp: &mut Vec<String>) {
-    if let MediaFeature::Plain { name, value } = ss {
-        let resolve_name = name.to_css_string(PrinterOptions::default()).unwrap();
-        let mut rem_value = -1f32;
-
-        if let MediaFeatureValue::Length(qwe) = value {
-            if let lightningcss::values::length::Length::Value(v) = qwe {
-                // let u = v.to_px();
-                let (vv, unit) = v.to_unit_value();
-                // println!("vv {} , unit {}", vv, unit);
-                if unit.to_lowercase().contains("em") {
-                    rem_value = vv;
-                } else {
-                    let u = v.to_px().unwrap_or_default();
-                    rem_value = (u / 16f32).round();
-                }
-            }
-            let yyy = search_media(&resolve_name, &rem_value);
-            temp.extend_from_slice(&yyy);
-        }
-    } else if let MediaFeature::Range {
-        name: _,
-        operator,
-        value,
-    } = ss
-    {
-        let operator_token = match operator {
-            MediaFeatureComparison::Equal => MinifiedMediaFeatureComparison::At,
-            MediaFeatureComparison::GreaterThan => MinifiedMediaFeatureComparison::Gt,
-            MediaFeatureComparison::GreaterThanEqual => MinifiedMediaFeatureComparison::Gt,
-            MediaFeatureComparison::LessThan => MinifiedMediaFeatureComparison::Lt,
-            MediaFeatureComparison::LessThanEqual => MinifiedMediaFeatureComparison::Lt,
-        };
-        let mut media_token: Vec<String> = vec![];
-        if let MediaFeatureValue::Length(qwe) = value {
-            let mut rem_value = -1f32;
-            if let lightningcss::values::length::Length::Value(v) = qwe {
-                let (vv, unit) = v.to_unit_value();
-                if unit.to_lowercase().contains("em") {
-                    rem_value = vv;
-                } else {
-                    let u = v.to_px().unwrap_or_default();
-                    rem_value = (u / 16f32).round();
-                }
-            }
-            media_token = search_media_v2(&operator, &rem_value);
-        }
-        match operator_token {
-            MinifiedMediaFeatureComparison::At => {
-                let t = media_token
-                    .into_iter()
-                    .find_or_first(|x| x.starts_with("at-"))
-                    .unwrap();
-                temp.push(t.to_owned());
-            }
-            MinifiedMediaFeatureComparison::Gt => {
-                let t = media_token
-                    .into_iter()
-                    .find_or_first(|x| x.starts_with("gt-"))
-                    .unwrap();
-                temp.push(t.to_owned());
+/// push the variant of a feature, or the arbitrary variant when it has none
+fn resolve_media_query_feat(theme: &Theme, ss: MediaFeature, temp: &mut Vec<String>) {
+    let resolved_count = temp.len();
+    let arbitrary_variant = arbitrary_media_variant(&ss);
+    resolve_media_query_width(theme, ss, temp);
+    if temp.len() == resolved_count {
+        temp.push(arbitrary_variant);
+    }
+}
+
+fn resolve_media_query_width(theme: &Theme, ss: MediaFeature, temp: &mut Vec<String>) {
+    match ss {
+        // lightningcss parses `min-width` / `max-width` into a `Range`
+        MediaFeature::Plain {
+            name,
+            value: MediaFeatureValue::Ident(ident),
+        } => {
+            let resolve_name = name
+                .to_css_string(PrinterOptions::default())
+                .unwrap_or_default();
+            if let Some(variant) = media_feature_variant(theme, &resolve_name, ident.as_ref()) {
+                temp.push(variant.to_owned());
             }
-            MinifiedMediaFeatureComparison::Lt => {
-                let t = media_token
-                    .into_iter()
-                    .find_or_first(|x| x.starts_with("lt-"))
-                    .unwrap();
-                temp.push(t.to_owned());
+        }
+        MediaFeature::Range {
+            name,
+            operator,
+            value,
+        } if is_width_feature(&name) => resolve_media_width(theme, operator, &value, temp),
+        // `(600px <= width < 900px)` is `(width >= 600px)` and `(width < 900px)`
+        MediaFeature::Interval {
+            name,
+            start,
+            start_operator,
+            end,
+            end_operator,
+        } if is_width_feature(&name) => {
+            let start_operator = match start_operator {
+                MediaFeatureComparison::LessThan => MediaFeatureComparison::GreaterThan,
+                MediaFeatureComparison::LessThanEqual => MediaFeatureComparison::GreaterThanEqual,
+                MediaFeatureComparison::GreaterThan => MediaFeatureComparison::LessThan,
+                MediaFeatureComparison::GreaterThanEqual => MediaFeatureComparison::LessThanEqual,
+                MediaFeatureComparison::Equal => MediaFeatureComparison::Equal,
+            };
+            let mut widths = vec![];
+            resolve_media_width(theme, start_operator, &start, &mut widths);
+            resolve_media_width(theme, end_operator, &end, &mut widths);
+            // both ends or the arbitrary variant of the whole interval
+            if widths.len() == 2 {
+                temp.extend(widths);
             }
         }
+        _ => (),
+    }
+}
+
+fn is_width_feature<F: ToCss>(name: &F) -> bool {
+    name.to_css_string(PrinterOptions::default())
+        .map(|name| name == "width")
+        .unwrap_or(false)
+}
+
+/// the breakpoint of the theme at the width, `md` / `lt-md`, else the arbitrary
+/// `min-[600px]` / `max-[37.5rem]`. `min-[]` is inclusive and `max-[]` is exclusive, so
+/// `width > 600px` is `min-[600.1px]` and `max-width: 600px` is `max-[600.1px]`.
+/// `width = 600px` and a width that is not a length are left to the caller
+fn resolve_media_width(
+    theme: &Theme,
+    operator: MediaFeatureComparison,
+    value: &MediaFeatureValue,
+    temp: &mut Vec<String>,
+) {
+    let MediaFeatureValue::Length(length) = value else {
+        return;
+    };
+    let Some(px) = media_width_px(length) else {
+        return;
+    };
+    // the arbitrary width, one step up when the sense of the query and the variant differ
+    let arbitrary_width = |steps: f32| {
+        media_width_step(length, steps)
+            .and_then(|width| width.to_css_string(PrinterOptions::default()).ok())
+            .unwrap_or_default()
+    };
+    match operator {
+        MediaFeatureComparison::GreaterThan | MediaFeatureComparison::GreaterThanEqual => {
+            let exclusive = operator == MediaFeatureComparison::GreaterThan;
+            temp.push(theme.search_media_min(px, exclusive).unwrap_or_else(|| {
+                format!("min-[{}]", arbitrary_width(if exclusive { 1f32 } else { 0f32 }))
+            }));
+        }
+        MediaFeatureComparison::LessThan | MediaFeatureComparison::LessThanEqual => {
+            let exclusive = operator == MediaFeatureComparison::LessThan;
+            temp.push(theme.search_media_max(px, exclusive).unwrap_or_else(|| {
+                format!("max-[{}]", arbitrary_width(if exclusive { 0f32 } else { 1f32 }))
+            }));
+        }
+        MediaFeatureComparison::Equal => (),
     }
 }
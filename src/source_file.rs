@@ -0,0 +1,90 @@
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+pub const DEFAULT_INCLUDE: &str = "**/*.{css,scss,sass}";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourceFile {
+    pub path: PathBuf,
+    /// path relative to the input directory, used to mirror the input tree in the output
+    pub relative_path: PathBuf,
+}
+
+impl SourceFile {
+    pub fn extension(&self) -> String {
+        self.path
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_default()
+    }
+
+    /// `<output_dir>/<relative_path>.<extension>`
+    pub fn output_path(&self, output_dir: &Path, extension: &str) -> PathBuf {
+        output_dir
+            .join(&self.relative_path)
+            .with_extension(extension)
+    }
+}
+
+fn build_glob(pattern: &str) -> Result<Glob, globset::Error> {
+    GlobBuilder::new(pattern).literal_separator(true).build()
+}
+
+fn build_glob_set(patterns: &[String]) -> Result<GlobSet, globset::Error> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(build_glob(pattern)?);
+    }
+    builder.build()
+}
+
+/// walk the input files / directories recursively.
+/// files inside a directory are kept when they match one of `include` and none of `exclude`,
+/// the globs are matched against the path relative to that directory.
+/// a file given directly is always kept.
+pub fn collect_source_files(
+    inputs: &[PathBuf],
+    include: &[String],
+    exclude: &[String],
+) -> Result<Vec<SourceFile>, globset::Error> {
+    let include_set = if include.is_empty() {
+        build_glob_set(&[DEFAULT_INCLUDE.to_owned()])?
+    } else {
+        build_glob_set(include)?
+    };
+    let exclude_set = build_glob_set(exclude)?;
+
+    let mut file_list: Vec<SourceFile> = vec![];
+    for input in inputs {
+        if !input.is_dir() {
+            file_list.push(SourceFile {
+                path: input.to_owned(),
+                relative_path: PathBuf::from(input.file_name().unwrap_or_default()),
+            });
+            continue;
+        }
+
+        let mut dir_files: Vec<SourceFile> = WalkDir::new(input)
+            .follow_links(true)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .filter_map(|e| {
+                let relative_path = e.path().strip_prefix(input).ok()?.to_path_buf();
+                if include_set.is_match(&relative_path) && !exclude_set.is_match(&relative_path) {
+                    Some(SourceFile {
+                        path: e.path().to_path_buf(),
+                        relative_path,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+        dir_files.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+        file_list.extend(dir_files);
+    }
+
+    Ok(file_list)
+}
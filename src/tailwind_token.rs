@@ -1,16 +1,13 @@
 // use std::fmt::Format;
-use csv;
+use crate::color_distance::Lab;
+use indexmap::IndexMap;
 use lazy_static::lazy_static;
 use lightningcss::{
-    media_query::MediaFeatureComparison,
     // media_query::{MediaCondition, MediaFeature, MediaQuery},
     properties::font::{FontSize, LineHeight},
-    stylesheet::ParserOptions,
-    stylesheet::StyleAttribute,
     // traits::ToCss,
     values::color::CssColor,
-    values::length::{Length, LengthValue},
-    values::percentage::DimensionPercentage,
+    values::length::Length,
 };
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -18,9 +15,6 @@ lazy_static! {
     pub static ref PROPETY_SET: Regex = Regex::new(r"([\w|\-]+):([\s|-|#|(|)|$|\d|\w]+);").unwrap();
 }
 // use std::fs;
-pub static mut TAILWIND_TYPOGRAPHY_TOKEN: Vec<TypographyToken> = Vec::new();
-pub static mut TAILWIND_COLOR_TOKEN: Vec<ColorToken> = Vec::new();
-pub static mut TAILWIND_MEDIA_LAYOUT_TOKEN: Vec<MediaToken> = Vec::new();
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TailwindTokenSet {
@@ -28,8 +22,11 @@ pub struct TailwindTokenSet {
     // involved class
     pub involved_classnames: Vec<String>,
     pub tailwind_token: Vec<String>,
+    /// the tokens as a class attribute, `md:pt-4 md:hidden`
+    pub class_string: String,
     pub layer_group: String,
     pub media_query: Vec<String>,
+    /// the variants the tokens are prefixed with, `md:lt-lg:`
     pub media_query_prefix: Vec<String>,
 
     /// raw_property_count : for debug the count of css attrubutes / property
@@ -37,6 +34,62 @@ pub struct TailwindTokenSet {
 
     /// raw_property_count : for debug the count of css attrubutes / property
     pub raw_property_count: i32,
+
+    /// each resolved declaration and the tokens it produced, for the coverage report
+    pub declarations: Vec<DeclarationCoverage>,
+
+    /// values without a matched preset token, e.g. a color outside the color preset
+    pub unresolved: Vec<UnresolvedValue>,
+
+    /// colors matched to a token of a different value, with the distance
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub approximate_colors: Vec<ColorMatch>,
+
+    /// the custom properties `--name: value` declared by the rule
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub variables: IndexMap<String, String>,
+
+    /// tokens pushed since `start_declaration`
+    #[serde(skip)]
+    pending_token: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum UnresolvedKind {
+    Color,
+    MediaQuery,
+    /// a `var()` without a declaration or a fallback
+    Variable,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UnresolvedValue {
+    pub kind: UnresolvedKind,
+    pub value: String,
+}
+
+/// a color of the stylesheet and the nearest color token
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ColorMatch {
+    pub value: String,
+    pub token: String,
+    /// the CIEDE2000 distance to the token color
+    pub delta_e: f32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeclarationCoverage {
+    pub property: String,
+    pub value: String,
+    /// empty when the declaration is dropped
+    pub tokens: Vec<String>,
+}
+
+impl DeclarationCoverage {
+    pub fn is_converted(&self) -> bool {
+        !self.tokens.is_empty()
+    }
 }
 impl TailwindTokenSet {
     pub fn new() -> TailwindTokenSet {
@@ -44,11 +97,17 @@ impl TailwindTokenSet {
             is_based: false,
             involved_classnames: Vec::new(),
             tailwind_token: Vec::new(),
+            class_string: String::new(),
             layer_group: String::new(),
             media_query: Vec::new(),
             media_query_prefix: Vec::new(),
             raw_property: String::new(),
             raw_property_count: 0i32,
+            declarations: Vec::new(),
+            unresolved: Vec::new(),
+            approximate_colors: Vec::new(),
+            variables: IndexMap::new(),
+            pending_token: Vec::new(),
         }
     }
 
@@ -68,6 +127,29 @@ impl TailwindTokenSet {
     pub fn push_media_queries(&mut self, income_arr: &Vec<String>) {
         self.media_query.extend_from_slice(income_arr)
     }
+    /// prefix the tokens with the variants of each query of a media query list,
+    /// `(max-width: 600px), print` repeats the tokens under `lt-sm:` and `print:`.
+    /// a query without a variant, `screen`, matches everywhere and keeps the tokens
+    pub fn apply_media_prefix(&mut self, prefixes: &[String]) {
+        if prefixes.is_empty() || prefixes.iter().any(|prefix| prefix.is_empty()) {
+            return;
+        }
+        self.media_query_prefix.extend_from_slice(prefixes);
+        let prefixed = |tokens: &[String]| -> Vec<String> {
+            prefixes
+                .iter()
+                .flat_map(|prefix| tokens.iter().map(move |token| format!("{prefix}{token}")))
+                .collect()
+        };
+        self.tailwind_token = prefixed(&self.tailwind_token);
+        for declaration in self.declarations.iter_mut() {
+            declaration.tokens = prefixed(&declaration.tokens);
+        }
+    }
+    /// join the tokens into `class_string`, once every token is pushed
+    pub fn update_class_string(&mut self) {
+        self.class_string = self.tailwind_token.join(" ");
+    }
     pub fn push_tailwind_token<F: ToString>(&mut self, property_name: &str, property_value: F) {
         let mut combind_token: String = property_value.to_string();
         // let mut existed: Option<usize> = None;
@@ -86,6 +168,7 @@ impl TailwindTokenSet {
         //     }
         // }
 
+        self.pending_token.push(combind_token.to_owned());
         self.tailwind_token.push(combind_token);
     }
     pub fn push_tailwind_token_with_check<F: ToString>(
@@ -108,6 +191,7 @@ impl TailwindTokenSet {
             self.tailwind_token.swap_remove(existed_index);
         }
 
+        self.pending_token.push(combind_token.to_owned());
         self.tailwind_token.push(combind_token);
     }
     pub fn set_raw_property(&mut self, income_str: &str) {
@@ -117,6 +201,35 @@ impl TailwindTokenSet {
         self.raw_property_count = income;
     }
 
+    pub fn push_unresolved(&mut self, kind: UnresolvedKind, value: &str) {
+        self.unresolved.push(UnresolvedValue {
+            kind,
+            value: value.to_owned(),
+        });
+    }
+    pub fn push_approximate_color(&mut self, value: &str, token: &str, delta_e: f32) {
+        self.approximate_colors.push(ColorMatch {
+            value: value.to_owned(),
+            token: token.to_owned(),
+            delta_e,
+        });
+    }
+    pub fn unresolved_count(&self, kind: UnresolvedKind) -> usize {
+        self.unresolved.iter().filter(|u| u.kind == kind).count()
+    }
+
+    pub fn start_declaration(&mut self) {
+        self.pending_token.clear();
+    }
+    /// record the tokens pushed since `start_declaration` for the declaration
+    pub fn finish_declaration(&mut self, property: &str, value: &str) {
+        self.declarations.push(DeclarationCoverage {
+            property: property.to_owned(),
+            value: value.to_owned(),
+            tokens: std::mem::take(&mut self.pending_token),
+        });
+    }
+
     pub fn export_token() -> Vec<String> {
         vec![]
     }
@@ -140,6 +253,16 @@ pub struct ColorToken {
     pub color_set_red: u8,
     pub color_set_green: u8,
     pub color_set_blue: u8,
+    /// 255 for an opaque color, 0 for `transparent`
+    #[serde(default = "opaque_alpha")]
+    pub color_set_alpha: u8,
+    /// `None` when the token is not an rgb color, `inherit`, `currentColor` ..
+    #[serde(skip)]
+    pub lab: Option<Lab>,
+}
+
+fn opaque_alpha() -> u8 {
+    255u8
 }
 impl ColorToken {
     pub fn get_token(&self) -> String {
@@ -188,6 +311,18 @@ pub struct TypographyToken {
 //     }
 // }
 
+/// a step of a size scale of a theme config, e.g. `maxWidth` `md` `28rem`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SizeToken {
+    /// the theme key, `width`, `spacing`, `borderRadius` ..
+    pub scale: String,
+    pub token_name: String,
+    pub token_value: String,
+    /// `token_value` in px, `None` when it is not a px / rem length
+    #[serde(skip)]
+    pub px: Option<f32>,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MediaToken {
     pub token_name: String,
@@ -196,285 +331,3 @@ pub struct MediaToken {
     pub min_width: Option<Length>,
     pub max_width: Option<Length>,
 }
-
-pub fn init() {
-    // fs::read_to_string("./preset/color-token.csv");
-
-    let mut csv_color_token = csv::Reader::from_path("./preset/color-token.csv").unwrap();
-    for record in csv_color_token.records() {
-        let raw_record = record.unwrap();
-        let mut color_token_set: ColorToken = ColorToken {
-            token_name: raw_record.get(0).unwrap().to_owned(),
-            token_value: raw_record.get(1).unwrap().to_owned(),
-            color_set: CssColor::CurrentColor,
-            color_set_red: 0u8,
-            color_set_green: 0u8,
-            color_set_blue: 0u8,
-        };
-        let dummy_color_set = format!("color: {};", color_token_set.token_value);
-        let mut css_attr =
-            StyleAttribute::parse(&dummy_color_set, ParserOptions::default()).unwrap();
-        for t in css_attr.declarations.iter_mut() {
-            // println!("t {}" , t.to_css_string(false, PrinterOptions::default()).unwrap());
-
-            if let lightningcss::properties::Property::Color(p) = t {
-                // println!("ccc {}" , p.to_rgb().to_css_string(PrinterOptions::default()).unwrap());
-
-                color_token_set.color_set = p.to_owned();
-                // // println!()
-                // let rgb_set = color_token_set.color_set.to_rgb();
-                if let CssColor::RGBA(_) = p {
-                    let rgb_set = p.to_rgb();
-                    if let Ok(CssColor::RGBA(pp)) = rgb_set {
-                        color_token_set.color_set_red = pp.red;
-                        color_token_set.color_set_green = pp.green;
-                        color_token_set.color_set_blue = pp.blue;
-                    }
-                }
-            }
-        }
-        unsafe {
-            TAILWIND_COLOR_TOKEN.push(color_token_set);
-        }
-    }
-
-    let mut csv_typography_token = csv::Reader::from_path("./preset/typography-token.csv").unwrap();
-    for record in csv_typography_token.records() {
-        let raw_record = record.unwrap();
-        let mut type_token_set: TypographyToken = TypographyToken {
-            token_name: raw_record.get(0).unwrap().to_owned(),
-            font_size: raw_record.get(1).unwrap().to_owned(),
-            line_height: raw_record.get(2).unwrap().to_owned(),
-            font_size_set: FontSize::Relative(
-                lightningcss::properties::font::RelativeFontSize::Larger,
-            ),
-            line_height_set: LineHeight::Length(
-                lightningcss::values::percentage::DimensionPercentage::Percentage(
-                    lightningcss::values::percentage::Percentage(100f32),
-                ),
-            ),
-        };
-        let dummy_set = format!(
-            "font-size: {}; line-height: {};",
-            type_token_set.font_size, type_token_set.line_height
-        );
-        let mut css_attr = StyleAttribute::parse(&dummy_set, ParserOptions::default()).unwrap();
-        for t in css_attr.declarations.iter_mut() {
-            if let lightningcss::properties::Property::FontSize(p) = t {
-                type_token_set.font_size_set = p.to_owned();
-            }
-            if let lightningcss::properties::Property::LineHeight(p) = t {
-                type_token_set.line_height_set = p.to_owned();
-            }
-        }
-        unsafe {
-            TAILWIND_TYPOGRAPHY_TOKEN.push(type_token_set);
-        }
-    }
-
-    let mut csv_media_query_token = csv::Reader::from_path("./preset/media-query.csv").unwrap();
-    for record in csv_media_query_token.records() {
-        let raw_record = record.unwrap().clone();
-        let mut token_set: MediaToken = MediaToken {
-            token_name: raw_record.get(0).unwrap().to_owned(),
-            min_width_string: raw_record.get(1).unwrap().to_string(),
-            max_width_string: raw_record.get(2).unwrap().to_string(),
-            min_width: None,
-            max_width: None,
-        };
-        let re = Regex::new(r"(?P<number_value>[\d|.]+)(?P<unit>\w+)$").unwrap();
-
-        let mut min_width_value = Length::Value(LengthValue::Rem(0f32));
-
-        if &token_set.min_width_string != "" {
-            let ssss = re.captures(&token_set.min_width_string).unwrap();
-            let num = ssss
-                .name("number_value")
-                .unwrap()
-                .as_str()
-                .to_owned()
-                .parse::<f32>()
-                .unwrap();
-            let unit = ssss.name("unit").unwrap().as_str().to_lowercase();
-
-            if unit == "rem" || unit == "em" {
-                min_width_value = Length::Value(LengthValue::Rem(num));
-            } else if unit == "px" {
-                min_width_value = Length::Value(LengthValue::Px(num));
-            }
-            token_set.min_width = Some(min_width_value);
-        }
-
-        let mut max_width_value = Length::Value(LengthValue::Rem(0f32));
-
-        if &token_set.max_width_string != "" {
-            let ssss = re.captures(&token_set.max_width_string).unwrap();
-            let num = ssss
-                .name("number_value")
-                .unwrap()
-                .as_str()
-                .to_owned()
-                .parse::<f32>()
-                .unwrap();
-            let unit = ssss.name("unit").unwrap().as_str().to_lowercase();
-
-            if unit == "rem" || unit == "em" {
-                max_width_value = Length::Value(LengthValue::Rem(num));
-            } else if unit == "px" {
-                max_width_value = Length::Value(LengthValue::Px(num));
-            }
-            token_set.max_width = Some(max_width_value);
-        }
-        // println!("{:?}" , token_set);
-        unsafe {
-            TAILWIND_MEDIA_LAYOUT_TOKEN.push(token_set);
-        }
-    }
-    // let mut leng_st = Length::Value(lightningcss::values::length::LengthValue::Rem(1f32));
-    // unsafe {
-    // println!(
-    //     "color-token : {}",
-    //     serde_json::to_string(&TAILWIND_COLOR_TOKEN).unwrap()
-    // );
-    // println!(
-    //     "typography-token : {}",
-    //     serde_json::to_string(&TAILWIND_TYPOGRAPHY_TOKEN).unwrap()
-    // );
-    // println!(
-    //     "media-token : {}",
-    //     serde_json::to_string(&TAILWIND_MEDIA_LAYOUT_TOKEN).unwrap()
-    // );
-    // }
-}
-
-fn in_range(a_range: &u8, income_val: &u8) -> bool {
-    let mut lower_val = a_range.to_owned();
-    let mut upper_val: u8 = a_range.to_owned();
-    if lower_val == 1u8 {
-        lower_val = 0u8;
-    } else if lower_val != 0u8 {
-        lower_val -= 2u8;
-    }
-    if upper_val == 254u8 {
-        upper_val = 255u8;
-    } else if upper_val != 255u8 {
-        upper_val += 2u8;
-    }
-    return (income_val >= &lower_val) && (income_val <= &upper_val);
-}
-
-pub fn search_color(r: &u8, g: &u8, b: &u8) -> Vec<String> {
-    let mut return_set: Vec<String> = vec![];
-    // if income_color
-
-    unsafe {
-        for color_set in &TAILWIND_COLOR_TOKEN {
-            if (&color_set.color_set_red == r)
-                && (&color_set.color_set_blue == b)
-                && (&color_set.color_set_green == g)
-            {
-                return_set.push(color_set.token_name.clone());
-            } else if in_range(&color_set.color_set_red, r)
-                && in_range(&color_set.color_set_blue, b)
-                && in_range(&color_set.color_set_green, g)
-            {
-                return_set.push(color_set.token_name.clone());
-            }
-        }
-    }
-
-    return return_set;
-}
-
-fn in_range_media_query(a_range: &f32, income_val: &f32) -> bool {
-    let mut lower_val = a_range.to_owned();
-    let mut upper_val: f32 = a_range.to_owned();
-    if lower_val == 1f32 {
-        lower_val = 0f32;
-    } else if lower_val != 0f32 {
-        lower_val -= 0.2f32;
-    }
-    upper_val += 0.2f32;
-    return (income_val > &lower_val) && (income_val <= &upper_val);
-}
-pub fn search_media(name: &str, value: &f32) -> Vec<String> {
-    let mut token: Vec<String> = Vec::new();
-    unsafe {
-        for media_set in &TAILWIND_MEDIA_LAYOUT_TOKEN {
-            println!("{}", media_set.token_name);
-            if name.to_lowercase() == "min-width" && media_set.min_width.is_some() {
-                if let Length::Value(d) = media_set.min_width.to_owned().unwrap() {
-                    let (ss, _) = d.to_unit_value();
-                    if in_range_media_query(&ss, value) {
-                        token.push(media_set.token_name.to_owned());
-                    }
-                }
-            } else if name.to_lowercase() == "max-width" && media_set.max_width.is_some() {
-                if let Length::Value(d) = media_set.max_width.to_owned().unwrap() {
-                    let (ss, _) = d.to_unit_value();
-                    if in_range_media_query(&ss, value) {
-                        token.push(media_set.token_name.to_owned());
-                    }
-                }
-            }
-        }
-    }
-
-    return token;
-}
-
-pub fn search_media_v2(operator: &MediaFeatureComparison, value: &f32) -> Vec<String> {
-    let mut token: Vec<String> = Vec::new();
-    unsafe {
-        for media_set in &TAILWIND_MEDIA_LAYOUT_TOKEN {
-            if (operator == &MediaFeatureComparison::GreaterThan
-                || operator == &MediaFeatureComparison::GreaterThanEqual)
-                && media_set.min_width.is_some()
-            {
-                if let Length::Value(d) = media_set.min_width.to_owned().unwrap() {
-                    let (ss, _) = d.to_unit_value();
-                    if in_range_media_query(&ss, value) {
-                        token.push(media_set.token_name.to_owned());
-                    }
-                }
-            } else if (operator == &MediaFeatureComparison::LessThan
-                || operator == &MediaFeatureComparison::LessThanEqual)
-                && media_set.max_width.is_some()
-            {
-                if let Length::Value(d) = media_set.max_width.to_owned().unwrap() {
-                    let (ss, _) = d.to_unit_value();
-                    if in_range_media_query(&ss, value) {
-                        token.push(media_set.token_name.to_owned());
-                    }
-                }
-            }
-        }
-    }
-    return token;
-}
-
-pub fn search_font(income_value: &f32) -> Vec<String> {
-    let mut token: Vec<String> = Vec::new();
-
-    unsafe {
-        for media_set in &TAILWIND_TYPOGRAPHY_TOKEN {
-            if let FontSize::Length(s) = &media_set.font_size_set {
-                if let DimensionPercentage::Dimension(d) = s {
-                    let mut rem_value = 0f32;
-                    let (value, unit) = d.to_unit_value();
-                    if unit.to_lowercase().contains("em") {
-                        rem_value = value;
-                    } else if d.to_px().is_some() {
-                        let u = d.to_px().unwrap_or_default();
-                        rem_value = (u / 16f32).round();
-                    }
-                    if &rem_value == income_value {
-                        // println!("fs - {} , income : {}", rem_value, income_value);
-                        token.push(media_set.token_name.to_owned());
-                    }
-                }
-            }
-        }
-    }
-    return token;
-}
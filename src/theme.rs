@@ -0,0 +1,915 @@
+use crate::color_distance::Lab;
+use crate::config::{ColorValue, FontSizeValue, ScreenValue, StringOrList, TailwindConfigSet};
+use crate::error::{Result, RewindError};
+use crate::tailwind_token::{ColorToken, MediaToken, SizeToken, TypographyToken};
+use crate::theme_config::{is_theme_config, read_theme_config};
+use indexmap::IndexMap;
+use lazy_static::lazy_static;
+use lightningcss::{
+    properties::font::{FontSize, LineHeight},
+    properties::{Property, PropertyId},
+    stylesheet::{ParserOptions, PrinterOptions, StyleAttribute},
+    values::color::CssColor,
+    values::length::{Length, LengthValue},
+    values::percentage::DimensionPercentage,
+    vendor_prefix::VendorPrefix,
+};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// read the rows of a preset csv by token name, a row that can not be read or converted
+/// is skipped and returned in the warnings. a repeated token name is given to
+/// `join_duplicate` with the value of the first row, when it returns `false` the row is
+/// skipped as a duplicate. `path` is only used for the warnings
+fn read_preset_csv<T>(
+    path: &Path,
+    content: &str,
+    warnings: &mut Vec<RewindError>,
+    mut to_token: impl FnMut(&csv::StringRecord) -> std::result::Result<(String, T), String>,
+    mut join_duplicate: impl FnMut(&mut T, T) -> bool,
+) -> IndexMap<String, T> {
+    let mut reader = csv::Reader::from_reader(content.as_bytes());
+    let mut tokens: IndexMap<String, T> = IndexMap::new();
+    for record in reader.records() {
+        match record {
+            Ok(raw_record) => {
+                let line = raw_record.position().map(|p| p.line()).unwrap_or_default();
+                match to_token(&raw_record) {
+                    Ok((name, token)) => match tokens.get_mut(&name) {
+                        Some(first) => {
+                            if !join_duplicate(first, token) {
+                                warnings.push(RewindError::preset(
+                                    path,
+                                    line,
+                                    format!("duplicate token `{}`", name),
+                                ));
+                            }
+                        }
+                        None => {
+                            tokens.insert(name, token);
+                        }
+                    },
+                    Err(message) => warnings.push(RewindError::preset(path, line, message)),
+                }
+            }
+            Err(err) => {
+                let line = err.position().map(|p| p.line()).unwrap_or_default();
+                warnings.push(RewindError::preset(path, line, err.to_string()));
+            }
+        }
+    }
+    tokens
+}
+
+fn record_field<'r>(
+    raw_record: &'r csv::StringRecord,
+    index: usize,
+    name: &str,
+) -> std::result::Result<&'r str, String> {
+    raw_record
+        .get(index)
+        .ok_or(format!("missing column `{}`", name))
+}
+
+fn color_from_record(
+    raw_record: &csv::StringRecord,
+) -> std::result::Result<(String, ColorValue), String> {
+    let token = color_token(
+        record_field(raw_record, 0, "token_name")?,
+        record_field(raw_record, 1, "token_value")?,
+    )?;
+    Ok((token.token_name, ColorValue::Color(token.token_value)))
+}
+
+/// `token_value` is any css color
+pub fn color_token(token_name: &str, token_value: &str) -> std::result::Result<ColorToken, String> {
+    let mut color_token_set: ColorToken = ColorToken {
+        token_name: token_name.to_owned(),
+        token_value: token_value.to_owned(),
+        color_set: CssColor::CurrentColor,
+        color_set_red: 0u8,
+        color_set_green: 0u8,
+        color_set_blue: 0u8,
+        color_set_alpha: 255u8,
+        lab: None,
+    };
+    let dummy_color_set = format!("color: {};", color_token_set.token_value);
+    let mut css_attr =
+        StyleAttribute::parse(&dummy_color_set, ParserOptions::default()).map_err(|err| {
+            format!(
+                "invalid color `{}`: {}",
+                color_token_set.token_value, err.kind
+            )
+        })?;
+    // `inherit` and the other css-wide keywords are not a `CssColor`
+    let mut is_color = ["inherit", "initial", "unset", "revert", "revert-layer"]
+        .contains(&token_value.trim().to_lowercase().as_str());
+    for t in css_attr.declarations.iter_mut() {
+        // println!("t {}" , t.to_css_string(false, PrinterOptions::default()).unwrap());
+
+        if let lightningcss::properties::Property::Color(p) = t {
+            // println!("ccc {}" , p.to_rgb().to_css_string(PrinterOptions::default()).unwrap());
+
+            color_token_set.color_set = p.to_owned();
+            is_color = true;
+            // // println!()
+            // let rgb_set = color_token_set.color_set.to_rgb();
+            if let CssColor::RGBA(_) = p {
+                let rgb_set = p.to_rgb();
+                if let Ok(CssColor::RGBA(pp)) = rgb_set {
+                    color_token_set.color_set_red = pp.red;
+                    color_token_set.color_set_green = pp.green;
+                    color_token_set.color_set_blue = pp.blue;
+                    color_token_set.color_set_alpha = pp.alpha;
+                    color_token_set.lab = Some(Lab::from_rgb(pp.red, pp.green, pp.blue));
+                }
+            }
+        }
+    }
+    if !is_color {
+        return Err(format!("invalid color `{}`", color_token_set.token_value));
+    }
+    Ok(color_token_set)
+}
+
+fn font_size_from_record(
+    raw_record: &csv::StringRecord,
+) -> std::result::Result<(String, FontSizeValue), String> {
+    let token = typography_token(
+        record_field(raw_record, 0, "token_name")?,
+        record_field(raw_record, 1, "font_size")?,
+        record_field(raw_record, 2, "line_height")?,
+    )?;
+    Ok((
+        token.token_name,
+        FontSizeValue::SizeLineHeight(token.font_size, token.line_height),
+    ))
+}
+
+pub fn typography_token(
+    token_name: &str,
+    font_size: &str,
+    line_height: &str,
+) -> std::result::Result<TypographyToken, String> {
+    let mut type_token_set: TypographyToken = TypographyToken {
+        token_name: token_name.to_owned(),
+        font_size: font_size.to_owned(),
+        line_height: line_height.to_owned(),
+        font_size_set: FontSize::Relative(lightningcss::properties::font::RelativeFontSize::Larger),
+        line_height_set: LineHeight::Length(
+            lightningcss::values::percentage::DimensionPercentage::Percentage(
+                lightningcss::values::percentage::Percentage(100f32),
+            ),
+        ),
+    };
+    let dummy_set = format!(
+        "font-size: {}; line-height: {};",
+        type_token_set.font_size, type_token_set.line_height
+    );
+    let mut css_attr = StyleAttribute::parse(&dummy_set, ParserOptions::default())
+        .map_err(|err| format!("invalid typography `{}`: {}", dummy_set, err.kind))?;
+    for t in css_attr.declarations.iter_mut() {
+        if let lightningcss::properties::Property::FontSize(p) = t {
+            type_token_set.font_size_set = p.to_owned();
+        }
+        if let lightningcss::properties::Property::LineHeight(p) = t {
+            type_token_set.line_height_set = p.to_owned();
+        }
+    }
+    Ok(type_token_set)
+}
+
+lazy_static! {
+    static ref MEDIA_WIDTH: Regex = Regex::new(r"(?P<number_value>[\d|.]+)(?P<unit>\w+)$").unwrap();
+    /// a shade of a palette, `red-500`, a named token like `primary` is preferred over it
+    static ref PALETTE_SHADE: Regex = Regex::new(r"^(\w+)-(\d+)").unwrap();
+}
+
+/// `48rem` / `768px` of the media-query.csv
+pub fn parse_media_width(width_string: &str) -> std::result::Result<Length, String> {
+    let ssss = MEDIA_WIDTH
+        .captures(width_string)
+        .ok_or(format!("invalid width `{}`", width_string))?;
+    let num = ssss["number_value"]
+        .parse::<f32>()
+        .map_err(|_| format!("invalid width `{}`", width_string))?;
+    let unit = ssss["unit"].to_lowercase();
+
+    if unit == "rem" || unit == "em" {
+        Ok(Length::Value(LengthValue::Rem(num)))
+    } else if unit == "px" {
+        Ok(Length::Value(LengthValue::Px(num)))
+    } else {
+        Err(format!("unsupported unit `{}`", unit))
+    }
+}
+
+/// a row is the exact range of the token, the screen is not expanded to the
+/// `at-` / `gt-` / `lt-` variants, the csv lists them
+fn screen_from_record(
+    raw_record: &csv::StringRecord,
+) -> std::result::Result<(String, ScreenValue), String> {
+    let token = media_token(
+        record_field(raw_record, 0, "token_name")?,
+        record_field(raw_record, 1, "min_width")?,
+        record_field(raw_record, 2, "max_width")?,
+    )?;
+    let width = |width: String| Some(width).filter(|w| !w.is_empty());
+    Ok((
+        token.token_name,
+        ScreenValue::Range {
+            min: width(token.min_width_string),
+            max: width(token.max_width_string),
+        },
+    ))
+}
+
+/// an empty `min_width` / `max_width` is an open end
+pub fn media_token(
+    token_name: &str,
+    min_width: &str,
+    max_width: &str,
+) -> std::result::Result<MediaToken, String> {
+    let mut token_set: MediaToken = MediaToken {
+        token_name: token_name.to_owned(),
+        min_width_string: min_width.to_string(),
+        max_width_string: max_width.to_string(),
+        min_width: None,
+        max_width: None,
+    };
+    if !token_set.min_width_string.is_empty() {
+        token_set.min_width = Some(parse_media_width(&token_set.min_width_string)?);
+    }
+    if !token_set.max_width_string.is_empty() {
+        token_set.max_width = Some(parse_media_width(&token_set.max_width_string)?);
+    }
+    // println!("{:?}" , token_set);
+    Ok(token_set)
+}
+
+/// the preset csv tables, each one is a file of a preset directory
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PresetKind {
+    Color,
+    Typography,
+    MediaQuery,
+}
+
+impl PresetKind {
+    pub const ALL: [PresetKind; 3] = [
+        PresetKind::Color,
+        PresetKind::Typography,
+        PresetKind::MediaQuery,
+    ];
+
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            PresetKind::Color => "color-token.csv",
+            PresetKind::Typography => "typography-token.csv",
+            PresetKind::MediaQuery => "media-query.csv",
+        }
+    }
+
+    /// the table of `preset/` built into the binary
+    fn embedded(&self) -> &'static str {
+        match self {
+            PresetKind::Color => include_str!("../preset/color-token.csv"),
+            PresetKind::Typography => include_str!("../preset/typography-token.csv"),
+            PresetKind::MediaQuery => include_str!("../preset/media-query.csv"),
+        }
+    }
+
+    /// the kind of a preset file, by its file name
+    pub fn from_path(path: &Path) -> Option<PresetKind> {
+        let file_name = path.file_name()?;
+        PresetKind::ALL
+            .into_iter()
+            .find(|kind| file_name == kind.file_name())
+    }
+
+    /// the theme model of the rows of a csv of this kind, with only the key of the kind
+    pub fn read_config(
+        &self,
+        path: &Path,
+        content: &str,
+        warnings: &mut Vec<RewindError>,
+    ) -> TailwindConfigSet {
+        let mut config = TailwindConfigSet::new();
+        match self {
+            // the rows of a repeated name are all colors of the token
+            PresetKind::Color => {
+                config.colors = Some(read_preset_csv(
+                    path,
+                    content,
+                    warnings,
+                    color_from_record,
+                    ColorValue::push_colors,
+                ))
+            }
+            PresetKind::Typography => {
+                config.font_size = Some(read_preset_csv(
+                    path,
+                    content,
+                    warnings,
+                    font_size_from_record,
+                    |_, _| false,
+                ))
+            }
+            PresetKind::MediaQuery => {
+                config.screens = Some(read_preset_csv(
+                    path,
+                    content,
+                    warnings,
+                    screen_from_record,
+                    |_, _| false,
+                ))
+            }
+        }
+        config
+    }
+
+    pub fn read_config_file(
+        &self,
+        path: &Path,
+        warnings: &mut Vec<RewindError>,
+    ) -> Result<TailwindConfigSet> {
+        let content = fs::read_to_string(path).map_err(|err| RewindError::io(path, err))?;
+        Ok(self.read_config(path, &content, warnings))
+    }
+}
+
+/// the theme built into the binary the presets are merged on
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BaseTheme {
+    /// the preset csv of `preset/`
+    #[default]
+    Custom,
+    /// the default theme of tailwind v3
+    Tailwind,
+    /// the default theme of the unocss `preset-uno`
+    Uno,
+    /// no token, only the presets
+    None,
+}
+
+impl BaseTheme {
+    /// the theme model of the base theme
+    pub fn config(&self, warnings: &mut Vec<RewindError>) -> Result<TailwindConfigSet> {
+        let (file_name, content) = match self {
+            BaseTheme::Custom => {
+                let mut config = TailwindConfigSet::new();
+                for kind in PresetKind::ALL {
+                    let path = Path::new("<embedded>").join(kind.file_name());
+                    config.merge(kind.read_config(&path, kind.embedded(), warnings));
+                }
+                // the csv have no size scale, the lengths, shadows and durations use the ones
+                // of tailwind
+                let tailwind = BaseTheme::Tailwind.config(warnings)?;
+                config.spacing = tailwind.spacing;
+                config.border_radius = tailwind.border_radius;
+                config.border_width = tailwind.border_width;
+                config.box_shadow = tailwind.box_shadow;
+                config.transition_duration = tailwind.transition_duration;
+                return Ok(config);
+            }
+            BaseTheme::None => return Ok(TailwindConfigSet::new()),
+            BaseTheme::Tailwind => (
+                "tailwind-v3.theme.json",
+                include_str!("../preset/tailwind-v3.theme.json"),
+            ),
+            BaseTheme::Uno => (
+                "unocss.theme.json",
+                include_str!("../preset/unocss.theme.json"),
+            ),
+        };
+        let path = Path::new("<embedded>").join(file_name);
+        let mut config = TailwindConfigSet::new();
+        config.merge(read_theme_config(&path, content, warnings)?);
+        Ok(config)
+    }
+}
+
+impl FromStr for BaseTheme {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "custom" => Ok(BaseTheme::Custom),
+            "tailwind" | "tailwind-v3" => Ok(BaseTheme::Tailwind),
+            "uno" | "unocss" => Ok(BaseTheme::Uno),
+            "none" => Ok(BaseTheme::None),
+            _ => Err(format!(
+                "`{}` is not a base theme, expected custom, tailwind, uno or none",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for BaseTheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BaseTheme::Custom => write!(f, "custom"),
+            BaseTheme::Tailwind => write!(f, "tailwind"),
+            BaseTheme::Uno => write!(f, "uno"),
+            BaseTheme::None => write!(f, "none"),
+        }
+    }
+}
+
+/// the utility syntax the tokens are written in
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Dialect {
+    /// unocss, the opacity of a color is `bg-red-500/50`, any percent
+    #[default]
+    Uno,
+    /// tailwind v3, `bg-red-500/50` on the steps of 5, else `bg-red-500/[.33]`
+    Tailwind,
+    /// tailwind v2 / windi, a separate `bg-opacity-50`
+    Legacy,
+}
+
+impl FromStr for Dialect {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "uno" | "unocss" => Ok(Dialect::Uno),
+            "tailwind" | "tailwind-v3" => Ok(Dialect::Tailwind),
+            "legacy" | "windi" | "tailwind-v2" => Ok(Dialect::Legacy),
+            _ => Err(format!(
+                "`{}` is not a dialect, expected uno, tailwind or legacy",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Dialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Dialect::Uno => write!(f, "uno"),
+            Dialect::Tailwind => write!(f, "tailwind"),
+            Dialect::Legacy => write!(f, "legacy"),
+        }
+    }
+}
+
+/// how a length is matched against the size scales of the theme
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SpacingMatch {
+    /// the step of the same value, else an arbitrary value `p-[13px]`
+    #[default]
+    Exact,
+    /// the nearest step up to the tolerance in px, else an arbitrary value
+    Tolerance(f32),
+    /// always an arbitrary value
+    Arbitrary,
+}
+
+impl FromStr for SpacingMatch {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "exact" => Ok(SpacingMatch::Exact),
+            "arbitrary" => Ok(SpacingMatch::Arbitrary),
+            tolerance => size_px(tolerance)
+                .filter(|px| *px >= 0f32)
+                .map(SpacingMatch::Tolerance)
+                .ok_or(format!(
+                    "`{}` is not exact, arbitrary or a tolerance like `2px` / `0.125rem`",
+                    s
+                )),
+        }
+    }
+}
+
+impl fmt::Display for SpacingMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpacingMatch::Exact => write!(f, "exact"),
+            SpacingMatch::Tolerance(px) => write!(f, "{}px", px),
+            SpacingMatch::Arbitrary => write!(f, "arbitrary"),
+        }
+    }
+}
+
+/// the options of matching the values of a stylesheet to the tokens
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MatchOptions {
+    pub spacing: SpacingMatch,
+    /// the largest CIEDE2000 distance of a color to its token
+    pub color_delta_e: f32,
+    /// the largest difference in px of a media query width to a breakpoint
+    pub breakpoint_tolerance: f32,
+}
+
+impl Default for MatchOptions {
+    fn default() -> MatchOptions {
+        MatchOptions {
+            spacing: SpacingMatch::default(),
+            color_delta_e: 2f32,
+            breakpoint_tolerance: 0f32,
+        }
+    }
+}
+
+/// a range of widths ends one step of its unit below the next one, `lt-md` ends at
+/// `41.9rem` where `md` starts at `42rem`, and `640px` follows `639.9px`
+const MEDIA_WIDTH_STEP: f32 = 0.1;
+
+/// `width` moved by `steps` of `MEDIA_WIDTH_STEP` in its own unit, `-1` is the width
+/// below it
+pub fn media_width_step(width: &Length, steps: f32) -> Option<Length> {
+    // 41.9 + 0.1 is not exactly 42 with the float math
+    let step = |number: f32| ((number + steps * MEDIA_WIDTH_STEP) * 1000f32).round() / 1000f32;
+    match width {
+        Length::Value(LengthValue::Px(px)) => Some(Length::Value(LengthValue::Px(step(*px)))),
+        Length::Value(LengthValue::Rem(rem)) => Some(Length::Value(LengthValue::Rem(step(*rem)))),
+        Length::Value(LengthValue::Em(em)) => Some(Length::Value(LengthValue::Em(step(*em)))),
+        _ => None,
+    }
+}
+
+/// a px / rem / em width of a media query in px, a rem and an em are 16px
+pub fn media_width_px(width: &Length) -> Option<f32> {
+    match width {
+        Length::Value(LengthValue::Rem(rem)) | Length::Value(LengthValue::Em(rem)) => {
+            Some(rem * 16f32)
+        }
+        Length::Value(value) => value.to_px(),
+        _ => None,
+    }
+}
+
+/// `1rem` / `16px` / `0` in px, `None` for the other units
+pub fn size_px(value: &str) -> Option<f32> {
+    let value = value.trim();
+    if let Some(px) = value.strip_suffix("px") {
+        px.trim().parse::<f32>().ok()
+    } else if let Some(rem) = value.strip_suffix("rem") {
+        rem.trim().parse::<f32>().ok().map(|rem| rem * 16f32)
+    } else {
+        value.parse::<f32>().ok().filter(|zero| *zero == 0f32)
+    }
+}
+
+/// `150ms` / `0.3s` in ms
+pub fn time_ms(value: &str) -> Option<f32> {
+    let value = value.trim();
+    if let Some(ms) = value.strip_suffix("ms") {
+        ms.trim().parse::<f32>().ok()
+    } else if let Some(s) = value.strip_suffix('s') {
+        s.trim().parse::<f32>().ok().map(|s| s * 1000f32)
+    } else {
+        None
+    }
+}
+
+/// the token tables a stylesheet is matched against, built from a theme model once and
+/// passed to `parse_to_tw_token`. a theme is only read while converting, so one can be
+/// shared by threads, and two themes can be used side by side
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Theme {
+    #[serde(rename = "color-token")]
+    pub color: Vec<ColorToken>,
+    #[serde(rename = "typography-token")]
+    pub typography: Vec<TypographyToken>,
+    #[serde(rename = "media-token")]
+    pub media: Vec<MediaToken>,
+    /// the size scales of a theme config
+    #[serde(rename = "size-token")]
+    pub size: Vec<SizeToken>,
+    /// the theme model the tables are built from
+    #[serde(skip)]
+    pub config: TailwindConfigSet,
+    #[serde(skip)]
+    pub matching: MatchOptions,
+    #[serde(skip)]
+    pub dialect: Dialect,
+}
+
+impl Theme {
+    /// a theme without any token
+    pub fn new() -> Theme {
+        Theme::default()
+    }
+
+    /// the base theme, then each of `presets` in order. a preset is a directory with any
+    /// of the preset csv, one csv named like `color-token.csv`, or a json / jsonc / js / ts
+    /// theme config. each one is read into the theme model and merged, its keys replace
+    /// the keys of the presets before it, its `theme.extend` adds to them. the skipped
+    /// rows and tokens are returned as warnings
+    pub fn from_presets(base: BaseTheme, presets: &[PathBuf]) -> Result<(Theme, Vec<RewindError>)> {
+        let mut warnings: Vec<RewindError> = Vec::new();
+        let mut config = base.config(&mut warnings)?;
+
+        for preset in presets {
+            if preset.is_dir() {
+                let mut found = false;
+                for kind in PresetKind::ALL {
+                    let path = preset.join(kind.file_name());
+                    if path.is_file() {
+                        config.merge(kind.read_config_file(&path, &mut warnings)?);
+                        found = true;
+                    }
+                }
+                if !found {
+                    return Err(RewindError::preset(
+                        preset,
+                        0,
+                        "no preset csv in the directory",
+                    ));
+                }
+            } else if is_theme_config(preset) {
+                let content =
+                    fs::read_to_string(preset).map_err(|err| RewindError::io(preset, err))?;
+                config.merge(read_theme_config(preset, &content, &mut warnings)?);
+            } else {
+                let kind = PresetKind::from_path(preset).ok_or_else(|| {
+                    RewindError::preset(
+                        preset,
+                        0,
+                        "unknown preset file, expected color-token.csv, typography-token.csv, media-query.csv or a .json / .jsonc / .js / .ts theme config",
+                    )
+                })?;
+                config.merge(kind.read_config_file(preset, &mut warnings)?);
+            }
+        }
+
+        // the tokens were checked while reading each preset
+        Ok((
+            Theme::from_config(config, Path::new("<theme>"), &mut Vec::new()),
+            warnings,
+        ))
+    }
+
+    /// the step of the first of `scales` that has one for `px`, as set by `matching.spacing`
+    pub fn search_size(&self, scales: &[&str], px: f32) -> Option<&SizeToken> {
+        let tolerance = match self.matching.spacing {
+            SpacingMatch::Exact => 0f32,
+            SpacingMatch::Tolerance(tolerance) => tolerance,
+            SpacingMatch::Arbitrary => return None,
+        };
+        scales.iter().find_map(|scale| {
+            self.size
+                .iter()
+                .filter(|token| token.scale == *scale)
+                // a bare `rounded` / `b` is the `DEFAULT` step, a bare `p` / `w` is no utility
+                .filter(|token| {
+                    token.token_name != "DEFAULT"
+                        || ["borderRadius", "borderWidth", "lineWidth"]
+                            .contains(&token.scale.as_str())
+                })
+                .filter_map(|token| token.px.map(|token_px| ((token_px - px).abs(), token)))
+                .filter(|(distance, _)| *distance <= tolerance + f32::EPSILON)
+                .min_by(|a, b| a.0.total_cmp(&b.0))
+                .map(|(_, token)| token)
+        })
+    }
+
+    /// the color tokens within `matching.color_delta_e` of the color, the nearest first,
+    /// with their CIEDE2000 distance. a token with an alpha only matches a color of the
+    /// same alpha, and any fully transparent color is `transparent`. of the same distance
+    /// a token of the same alpha comes first, then a named token before a palette shade
+    pub fn search_color(&self, r: &u8, g: &u8, b: &u8, a: &u8) -> Vec<(&ColorToken, f32)> {
+        let lab = Lab::from_rgb(*r, *g, *b);
+        let mut found: Vec<(&ColorToken, f32)> = self
+            .color
+            .iter()
+            .filter(|color_set| {
+                color_set.color_set_alpha == 255u8 || color_set.color_set_alpha == *a
+            })
+            .filter_map(|color_set| {
+                let token_lab = color_set.lab?;
+                if *a == 0u8 && color_set.color_set_alpha == 0u8 {
+                    return Some((color_set, 0f32));
+                }
+                Some((color_set, lab.delta_e(&token_lab) as f32))
+            })
+            .filter(|(_, delta_e)| *delta_e <= self.matching.color_delta_e)
+            .collect();
+        // the distance of the same color is not exactly 0 with the float math
+        let rounded = |delta_e: f32| (delta_e * 100f32).round();
+        // stable, the tokens that tie on all of it keep the order of the theme
+        found.sort_by(|x, y| {
+            rounded(x.1)
+                .total_cmp(&rounded(y.1))
+                .then_with(|| (x.0.color_set_alpha != *a).cmp(&(y.0.color_set_alpha != *a)))
+                .then_with(|| {
+                    PALETTE_SHADE
+                        .is_match(&x.0.token_name)
+                        .cmp(&PALETTE_SHADE.is_match(&y.0.token_name))
+                })
+        });
+        found
+    }
+
+    /// the breakpoint that starts at `px` and has no end, within
+    /// `matching.breakpoint_tolerance`. `gt-md` is preferred, or the plain `md` with the
+    /// tailwind dialect. the start of an exclusive `width > 41.9rem` is the width one step
+    /// above it, the `42rem` of `md`
+    pub fn search_media_min(&self, px: f32, exclusive: bool) -> Option<String> {
+        let steps = if exclusive { -1f32 } else { 0f32 };
+        let found: Vec<&MediaToken> = self
+            .media
+            .iter()
+            .filter(|media_set| media_set.max_width.is_none())
+            .filter(|media_set| {
+                media_set
+                    .min_width
+                    .as_ref()
+                    .and_then(|min| media_width_step(min, steps))
+                    .as_ref()
+                    .and_then(media_width_px)
+                    .is_some_and(|min| self.is_same_width(min, px))
+            })
+            .collect();
+        let preferred = match self.dialect {
+            Dialect::Tailwind => found
+                .iter()
+                .find(|media_set| !media_set.token_name.contains('-')),
+            _ => found
+                .iter()
+                .find(|media_set| media_set.token_name.starts_with("gt-")),
+        };
+        preferred
+            .or(found.first())
+            .map(|media_set| media_set.token_name.to_owned())
+    }
+
+    /// the breakpoint that ends at `px` and has no start, `lt-md`, or `max-md` with the
+    /// tailwind dialect. the end of an exclusive `width < 42rem` is the width one step
+    /// below it, the `41.9rem` of `lt-md`
+    pub fn search_media_max(&self, px: f32, exclusive: bool) -> Option<String> {
+        let steps = if exclusive { 1f32 } else { 0f32 };
+        let found: Vec<&MediaToken> = self
+            .media
+            .iter()
+            .filter(|media_set| media_set.min_width.is_none())
+            .filter(|media_set| {
+                media_set
+                    .max_width
+                    .as_ref()
+                    .and_then(|max| media_width_step(max, steps))
+                    .as_ref()
+                    .and_then(media_width_px)
+                    .is_some_and(|max| self.is_same_width(max, px))
+            })
+            .collect();
+        let token_name = found
+            .iter()
+            .find(|media_set| media_set.token_name.starts_with("lt-"))
+            .or(found.first())
+            .map(|media_set| media_set.token_name.to_owned())?;
+        match (self.dialect, token_name.strip_prefix("lt-")) {
+            (Dialect::Tailwind, Some(name)) => Some(format!("max-{}", name)),
+            _ => Some(token_name),
+        }
+    }
+
+    fn is_same_width(&self, a: f32, b: f32) -> bool {
+        // the px of a rem width is not exact with the float math
+        (a - b).abs() <= self.matching.breakpoint_tolerance + 0.001
+    }
+
+    /// the `transitionDuration` / uno `duration` step of `ms`, the `DEFAULT` is the
+    /// duration of a bare `transition` and not a `duration` utility
+    pub fn search_duration(&self, ms: f32) -> Option<&str> {
+        [&self.config.transition_duration, &self.config.duration]
+            .into_iter()
+            .flatten()
+            .flat_map(|durations| durations.iter())
+            .filter(|(token_name, _)| token_name.as_str() != "DEFAULT")
+            .find(|(_, token_value)| {
+                time_ms(token_value).is_some_and(|token_ms| (token_ms - ms).abs() < 0.001)
+            })
+            .map(|(token_name, _)| token_name.as_str())
+    }
+
+    /// the `boxShadow` step of a `box-shadow` value as lightningcss prints it, the
+    /// `var(--un-shadow-inset)` of the uno shadows is empty without `shadow-inset`
+    pub fn search_box_shadow(&self, value: &str) -> Option<&str> {
+        self.config
+            .box_shadow
+            .iter()
+            .flat_map(|shadows| shadows.iter())
+            .find(|(_, token_value)| {
+                let token_value = match token_value {
+                    StringOrList::String(shadow) => shadow.to_owned(),
+                    StringOrList::List(shadows) => shadows.join(", "),
+                };
+                Property::parse_string(
+                    PropertyId::BoxShadow(VendorPrefix::None),
+                    &token_value.replace("var(--un-shadow-inset)", ""),
+                    ParserOptions::default(),
+                )
+                .ok()
+                .and_then(|shadow| shadow.value_to_css_string(PrinterOptions::default()).ok())
+                .is_some_and(|shadow| shadow == value)
+            })
+            .map(|(token_name, _)| token_name.as_str())
+    }
+
+    pub fn search_font(&self, income_value: &f32) -> Vec<String> {
+        let mut token: Vec<String> = Vec::new();
+
+        for media_set in self.typography.iter() {
+            if let FontSize::Length(DimensionPercentage::Dimension(d)) = &media_set.font_size_set {
+                let mut rem_value = 0f32;
+                let (value, unit) = d.to_unit_value();
+                if unit.to_lowercase().contains("em") {
+                    rem_value = value;
+                } else if d.to_px().is_some() {
+                    let u = d.to_px().unwrap_or_default();
+                    rem_value = (u / 16f32).round();
+                }
+                if &rem_value == income_value {
+                    // println!("fs - {} , income : {}", rem_value, income_value);
+                    token.push(media_set.token_name.to_owned());
+                }
+            }
+        }
+        token
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_to_tw_token;
+
+    fn tokens(theme: &Theme, css: &str) -> Vec<String> {
+        parse_to_tw_token(theme, css, "test.css").unwrap()[0]
+            .tailwind_token
+            .clone()
+    }
+
+    #[test]
+    fn transparent_matches_the_transparent_token() {
+        for base in [BaseTheme::Custom, BaseTheme::Tailwind, BaseTheme::Uno] {
+            let (theme, _) = Theme::from_presets(base, &[]).unwrap();
+            let set = &parse_to_tw_token(&theme, ".a { background: transparent; }", "test.css")
+                .unwrap()[0];
+            assert_eq!(set.tailwind_token, vec!["bg-transparent"], "{}", base);
+            assert!(set.unresolved.is_empty(), "{}", base);
+        }
+    }
+
+    #[test]
+    fn named_token_is_preferred_over_a_palette_shade() {
+        let (mut theme, _) = Theme::from_presets(BaseTheme::None, &[]).unwrap();
+        theme.color = vec![
+            color_token("red-500", "#ef4444").unwrap(),
+            color_token("danger", "#ef4444").unwrap(),
+        ];
+        assert_eq!(tokens(&theme, ".a { color: #ef4444; }"), vec!["text-danger"]);
+    }
+
+    #[test]
+    fn shadows_and_durations_resolve_against_the_theme() {
+        for base in [BaseTheme::Custom, BaseTheme::Tailwind, BaseTheme::Uno] {
+            let (theme, _) = Theme::from_presets(base, &[]).unwrap();
+            let css = ".a {
+                box-shadow: 0 4px 6px -1px rgb(0 0 0 / 0.1), 0 2px 4px -2px rgb(0 0 0 / 0.1);
+                transition-duration: 0.3s;
+            }";
+            assert_eq!(
+                tokens(&theme, css),
+                vec!["duration-300", "shadow-md"],
+                "{}",
+                base
+            );
+            let css = ".a { box-shadow: 0 1px 3px 0 rgb(0 0 0 / 0.1), 0 1px 2px -1px rgb(0 0 0 / 0.1) }";
+            assert_eq!(
+                tokens(&theme, css),
+                vec!["shadow"],
+                "{}",
+                base
+            );
+        }
+    }
+
+    #[test]
+    fn shadows_and_durations_outside_the_theme_are_arbitrary() {
+        let (theme, _) = Theme::from_presets(BaseTheme::Tailwind, &[]).unwrap();
+        assert_eq!(
+            tokens(
+                &theme,
+                ".a { box-shadow: 0 0 2px red, 0 1px 1px blue; transition-duration: 250ms; }"
+            ),
+            vec!["duration-[250ms]", "shadow-[0_0_2px_red,0_1px_1px_#00f]"]
+        );
+        let (theme, _) = Theme::from_presets(BaseTheme::None, &[]).unwrap();
+        assert_eq!(
+            tokens(&theme, ".a { transition-duration: 300ms; }"),
+            vec!["duration-[300ms]"]
+        );
+    }
+}
@@ -0,0 +1,349 @@
+use crate::config::{ColorValue, ScreenValue, TailwindConfigSet};
+use crate::error::{Result, RewindError};
+use crate::tailwind_token::{MediaToken, SizeToken};
+use crate::theme::{
+    color_token, media_token, media_width_step, parse_media_width, size_px, typography_token,
+    Theme,
+};
+use crate::theme_js::{is_js_config, read_js_config};
+use indexmap::IndexMap;
+use lightningcss::values::length::{Length, LengthValue};
+use serde_json::{Map, Value};
+use std::path::Path;
+
+/// the file is a json / jsonc / js / ts theme config instead of a preset csv
+pub fn is_theme_config(path: &Path) -> bool {
+    is_js_config(path)
+        || path
+            .extension()
+            .map(|e| e.eq_ignore_ascii_case("json") || e.eq_ignore_ascii_case("jsonc"))
+            .unwrap_or(false)
+}
+
+/// blank out the `//` and `/* */` comments and the trailing commas of jsonc, the lines of
+/// the remaining text stay in place for the json errors
+pub fn strip_jsonc(content: &str) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    let mut stripped: Vec<char> = Vec::with_capacity(chars.len());
+    let mut in_string = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if in_string {
+            stripped.push(c);
+            if c == '\\' {
+                stripped.extend(next);
+                i += 1;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '/' && next == Some('/') {
+            while i < chars.len() && chars[i] != '\n' {
+                stripped.push(' ');
+                i += 1;
+            }
+            continue;
+        } else if c == '/' && next == Some('*') {
+            stripped.extend([' ', ' ']);
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                stripped.push(if chars[i] == '\n' { '\n' } else { ' ' });
+                i += 1;
+            }
+            if i < chars.len() {
+                stripped.extend([' ', ' ']);
+                i += 2;
+            }
+            continue;
+        } else {
+            in_string = c == '"';
+            stripped.push(c);
+        }
+        i += 1;
+    }
+
+    // `,` followed by `}` or `]`
+    let mut in_string = false;
+    let mut i = 0;
+    while i < stripped.len() {
+        let c = stripped[i];
+        if in_string {
+            if c == '\\' {
+                i += 1;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == ',' {
+            let closing = stripped[i + 1..]
+                .iter()
+                .find(|c| !c.is_whitespace())
+                .map(|c| *c == '}' || *c == ']')
+                .unwrap_or(false);
+            if closing {
+                stripped[i] = ' ';
+            }
+        }
+        i += 1;
+    }
+    stripped.into_iter().collect()
+}
+
+/// `{ "red": { "100": "#f00", "DEFAULT": "#e00" } }` to `red-100`, `red`
+fn flatten_colors(
+    colors: &IndexMap<String, ColorValue>,
+    prefix: &str,
+    tokens: &mut Vec<(String, String)>,
+) {
+    for (key, value) in colors {
+        let name = match (prefix.is_empty(), key.as_str()) {
+            (true, _) => key.to_owned(),
+            (false, "DEFAULT") => prefix.to_owned(),
+            (false, _) => format!("{}-{}", prefix, key),
+        };
+        match value {
+            ColorValue::Color(color) => tokens.push((name, color.to_owned())),
+            ColorValue::Colors(colors) => tokens.extend(
+                colors
+                    .iter()
+                    .map(|color| (name.to_owned(), color.to_owned())),
+            ),
+            ColorValue::Shades(shades) => flatten_colors(shades, &name, tokens),
+        }
+    }
+}
+
+fn width_in_rem(width: &Length) -> f32 {
+    match width {
+        Length::Value(LengthValue::Px(px)) => px / 16f32,
+        Length::Value(value) => value.to_unit_value().0,
+        _ => 0f32,
+    }
+}
+
+/// the width just below `width`, the max width of the range that ends at `width`
+fn width_below(width: &Length) -> String {
+    match media_width_step(width, -1f32) {
+        Some(Length::Value(value)) => {
+            let (number, unit) = value.to_unit_value();
+            format!("{}{}", number, unit)
+        }
+        _ => String::new(),
+    }
+}
+
+/// the media tokens of the `screens` of a config, each screen gets the same variants as
+/// the media-query.csv: `md` / `gt-md` from its width, `at-md` up to the next screen,
+/// `lt-md` below its width. `{ "min": .., "max": .. }` screens only get the plain token
+fn screen_tokens(
+    screens: &IndexMap<String, ScreenValue>,
+    warnings: &mut Vec<RewindError>,
+    path: &Path,
+) -> Vec<MediaToken> {
+    let mut widths: Vec<(String, String, Length)> = Vec::new();
+    let mut ranges: Vec<MediaToken> = Vec::new();
+    for (name, value) in screens {
+        let token = match value {
+            ScreenValue::Width(width) => parse_media_width(width)
+                .map(|length| widths.push((name.to_owned(), width.to_owned(), length))),
+            ScreenValue::Range { min, max } => media_token(
+                name,
+                min.as_deref().unwrap_or_default(),
+                max.as_deref().unwrap_or_default(),
+            )
+            .map(|token| ranges.push(token)),
+        };
+        if let Err(message) = token {
+            warnings.push(RewindError::preset(
+                path,
+                0,
+                format!("screens.{}: {}", name, message),
+            ));
+        }
+    }
+    widths.sort_by(|a, b| width_in_rem(&a.2).total_cmp(&width_in_rem(&b.2)));
+
+    let mut base = Vec::new();
+    let mut at = Vec::new();
+    let mut gt = Vec::new();
+    let mut lt = Vec::new();
+    for (i, (name, width, length)) in widths.iter().enumerate() {
+        let next_below = widths
+            .get(i + 1)
+            .map(|w| width_below(&w.2))
+            .unwrap_or_default();
+        let variants = [
+            (&mut base, name.to_owned(), width.to_owned(), String::new()),
+            (
+                &mut at,
+                format!("at-{}", name),
+                width.to_owned(),
+                next_below,
+            ),
+            (
+                &mut gt,
+                format!("gt-{}", name),
+                width.to_owned(),
+                String::new(),
+            ),
+            (
+                &mut lt,
+                format!("lt-{}", name),
+                String::new(),
+                width_below(length),
+            ),
+        ];
+        for (tokens, token_name, min, max) in variants {
+            if let Ok(token) = media_token(&token_name, &min, &max) {
+                tokens.push(token);
+            }
+        }
+    }
+    [base, at, gt, lt, ranges].concat()
+}
+
+/// `Height` of a hand written config is the `height` key
+fn normalize_key(key: &str) -> String {
+    let mut chars = key.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// the numbers of a config are read as the strings of the theme model, `lineHeight: 1.5`
+fn numbers_to_strings(value: &mut Value) {
+    match value {
+        Value::Number(n) => *value = Value::String(n.to_string()),
+        Value::Array(values) => values.iter_mut().for_each(numbers_to_strings),
+        Value::Object(map) => map.values_mut().for_each(numbers_to_strings),
+        _ => {}
+    }
+}
+
+/// read the keys of a theme object one by one, a key of the wrong shape is skipped
+/// and returned in the warnings
+fn read_theme_keys(
+    theme: &Map<String, Value>,
+    key_path: &str,
+    path: &Path,
+    warnings: &mut Vec<RewindError>,
+) -> TailwindConfigSet {
+    let mut config = TailwindConfigSet::new();
+    for (key, value) in theme.iter().filter(|(key, _)| *key != "extend") {
+        // unocss `breakpoints` are the `screens` of tailwind
+        let key = match normalize_key(key) {
+            key if key == "breakpoints" => "screens".to_owned(),
+            key => key,
+        };
+        let mut value = value.to_owned();
+        numbers_to_strings(&mut value);
+        let single = Value::Object(Map::from_iter([(key.to_owned(), value)]));
+        match TailwindConfigSet::from_json_value(single) {
+            Ok(key_config) => config.merge(key_config),
+            Err(err) => warnings.push(RewindError::preset(
+                path,
+                0,
+                format!("{}{}: {}", key_path, key, err),
+            )),
+        }
+    }
+    config
+}
+
+/// read the `theme` of a windi / uno / tailwind config in json or jsonc, or a file that
+/// is the theme object itself, or of a js / ts config, see `read_js_config`, into the
+/// theme model. the keys the theme model does not
+/// know are ignored, the values that are not a token are returned in the warnings
+pub fn read_theme_config(
+    path: &Path,
+    content: &str,
+    warnings: &mut Vec<RewindError>,
+) -> Result<TailwindConfigSet> {
+    let root: Value = if is_js_config(path) {
+        read_js_config(path, content, warnings)?
+    } else {
+        serde_json::from_str(&strip_jsonc(content))
+            .map_err(|err| RewindError::preset(path, 0, err.to_string()))?
+    };
+    let theme = match root.get("theme") {
+        Some(Value::Object(theme)) => theme,
+        _ => match &root {
+            Value::Object(theme) => theme,
+            _ => return Err(RewindError::preset(path, 0, "expected a json object")),
+        },
+    };
+
+    let mut config = read_theme_keys(theme, "", path, warnings);
+    if let Some(Value::Object(extend)) = theme.get("extend") {
+        config.extend = Some(Box::new(read_theme_keys(extend, "extend.", path, warnings)));
+    }
+
+    // the invalid tokens are reported with the file they come from
+    let mut tokens = TailwindConfigSet::new();
+    tokens.merge(config.clone());
+    Theme::from_config(tokens, path, warnings);
+    Ok(config)
+}
+
+impl Theme {
+    /// build the token tables of a theme model, `colors`, `screens` / `breakpoints`,
+    /// `fontSize` and the size scales are used, `boxShadow` and the durations are matched
+    /// against the model itself. the `extend` keys are expected to be merged, see
+    /// `TailwindConfigSet::merge`. a token that is not valid is skipped and returned in the
+    /// warnings, `path` is only used for the warnings
+    pub fn from_config(
+        config: TailwindConfigSet,
+        path: &Path,
+        warnings: &mut Vec<RewindError>,
+    ) -> Theme {
+        let mut theme = Theme::new();
+
+        let mut colors = Vec::new();
+        if let Some(config_colors) = &config.colors {
+            flatten_colors(config_colors, "", &mut colors);
+        }
+        for (name, value) in colors {
+            match color_token(&name, &value) {
+                Ok(token) => theme.color.push(token),
+                Err(message) => warnings.push(RewindError::preset(
+                    path,
+                    0,
+                    format!("colors.{}: {}", name, message),
+                )),
+            }
+        }
+
+        for screens in [&config.screens, &config.breakpoints].into_iter().flatten() {
+            theme.media.extend(screen_tokens(screens, warnings, path));
+        }
+
+        for (name, value) in config.font_size.iter().flatten() {
+            let line_height = value.line_height().unwrap_or("normal");
+            match typography_token(name, value.font_size(), line_height) {
+                Ok(token) => theme.typography.push(token),
+                Err(message) => warnings.push(RewindError::preset(
+                    path,
+                    0,
+                    format!("fontSize.{}: {}", name, message),
+                )),
+            }
+        }
+
+        for (scale, tokens) in config.size_scales() {
+            theme
+                .size
+                .extend(tokens.iter().map(|(token_name, token_value)| SizeToken {
+                    scale: scale.to_owned(),
+                    token_name: token_name.to_owned(),
+                    token_value: token_value.to_owned(),
+                    px: size_px(token_value),
+                }));
+        }
+
+        theme.config = config;
+        theme
+    }
+}
@@ -0,0 +1,768 @@
+use crate::error::{Result, RewindError};
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// the file is a `tailwind.config.js` / `uno.config.ts` like config
+pub fn is_js_config(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| {
+            ["js", "cjs", "mjs", "ts", "cts", "mts"]
+                .iter()
+                .any(|js| e.eq_ignore_ascii_case(js))
+        })
+        .unwrap_or(false)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum TokenKind {
+    Ident(String),
+    Str(String),
+    Number(String),
+    /// a template literal with `${}` in it
+    Template,
+    Punct(char),
+    /// `=>`, `...`, `?.` and the other operators
+    Operator(String),
+}
+
+#[derive(Clone, Debug)]
+struct Token {
+    kind: TokenKind,
+    line: u64,
+    start: usize,
+    end: usize,
+}
+
+/// a value of the config as written, what can not be evaluated statically is kept as
+/// `Dynamic` and reported when it is part of the theme
+#[derive(Clone, Debug)]
+enum JsValue {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<JsValue>),
+    Object(Vec<(String, JsValue)>),
+    Dynamic { line: u64, source: String },
+}
+
+fn tokenize(content: &str) -> Vec<Token> {
+    let chars: Vec<(usize, char)> = content.char_indices().collect();
+    let byte_at = |i: usize| chars.get(i).map(|c| c.0).unwrap_or(content.len());
+    let mut tokens = Vec::new();
+    let mut line = 1u64;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i].1;
+        let next = chars.get(i + 1).map(|c| c.1);
+        let start = i;
+        let start_line = line;
+        let kind = if c == '\n' {
+            line += 1;
+            i += 1;
+            continue;
+        } else if c.is_whitespace() {
+            i += 1;
+            continue;
+        } else if c == '/' && next == Some('/') {
+            while i < chars.len() && chars[i].1 != '\n' {
+                i += 1;
+            }
+            continue;
+        } else if c == '/' && next == Some('*') {
+            i += 2;
+            while i < chars.len()
+                && !(chars[i].1 == '*' && chars.get(i + 1).map(|c| c.1) == Some('/'))
+            {
+                if chars[i].1 == '\n' {
+                    line += 1;
+                }
+                i += 1;
+            }
+            i += 2;
+            continue;
+        } else if c == '"' || c == '\'' || c == '`' {
+            let mut value = String::new();
+            let mut dynamic = false;
+            i += 1;
+            while i < chars.len() && chars[i].1 != c {
+                let sc = chars[i].1;
+                if sc == '\n' {
+                    line += 1;
+                }
+                if sc == '\\' && i + 1 < chars.len() {
+                    i += 1;
+                    let escaped = chars[i].1;
+                    match escaped {
+                        'n' => value.push('\n'),
+                        't' => value.push('\t'),
+                        'r' => value.push('\r'),
+                        'u' => {
+                            let hex: String = chars[i + 1..].iter().take(4).map(|c| c.1).collect();
+                            match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                                Some(u) => {
+                                    value.push(u);
+                                    i += hex.len();
+                                }
+                                None => value.push('u'),
+                            }
+                        }
+                        '\n' => line += 1,
+                        _ => value.push(escaped),
+                    }
+                } else if c == '`' && sc == '$' && chars.get(i + 1).map(|c| c.1) == Some('{') {
+                    dynamic = true;
+                    value.push(sc);
+                } else {
+                    value.push(sc);
+                }
+                i += 1;
+            }
+            i += 1;
+            if dynamic {
+                TokenKind::Template
+            } else {
+                TokenKind::Str(value)
+            }
+        } else if c.is_ascii_digit() || (c == '.' && next.is_some_and(|n| n.is_ascii_digit())) {
+            while i < chars.len()
+                && (chars[i].1.is_ascii_alphanumeric() || chars[i].1 == '.' || chars[i].1 == '_')
+            {
+                i += 1;
+            }
+            let number: String = chars[start..i]
+                .iter()
+                .map(|c| c.1)
+                .filter(|c| *c != '_')
+                .collect();
+            TokenKind::Number(number)
+        } else if c.is_alphabetic() || c == '_' || c == '$' {
+            while i < chars.len()
+                && (chars[i].1.is_alphanumeric() || chars[i].1 == '_' || chars[i].1 == '$')
+            {
+                i += 1;
+            }
+            TokenKind::Ident(chars[start..i].iter().map(|c| c.1).collect())
+        } else if "{}[](),:;.=?".contains(c)
+            && !matches!(
+                (c, next),
+                ('=', Some('=' | '>')) | ('.', Some('.')) | ('?', Some('.' | '?'))
+            )
+        {
+            i += 1;
+            TokenKind::Punct(c)
+        } else {
+            let operator = [
+                "...", "=>", "?.", "??", "===", "!==", "==", "!=", "&&", "||",
+            ]
+            .into_iter()
+            .find(|op| content[byte_at(i)..].starts_with(op));
+            match operator {
+                Some(op) => {
+                    i += op.chars().count();
+                    TokenKind::Operator(op.to_owned())
+                }
+                None => {
+                    i += 1;
+                    TokenKind::Operator(c.to_string())
+                }
+            }
+        };
+        tokens.push(Token {
+            kind,
+            line: start_line,
+            start: byte_at(start),
+            end: byte_at(i),
+        });
+    }
+    tokens
+}
+
+struct Parser<'a> {
+    content: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+    /// the top level `const` / `let` / `var` declared so far
+    bindings: HashMap<String, JsValue>,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&TokenKind> {
+        self.tokens.get(self.pos).map(|t| &t.kind)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&TokenKind> {
+        self.tokens.get(self.pos + offset).map(|t| &t.kind)
+    }
+
+    fn is_punct(&self, c: char) -> bool {
+        self.peek() == Some(&TokenKind::Punct(c))
+    }
+
+    fn is_ident(&self, name: &str) -> bool {
+        matches!(self.peek(), Some(TokenKind::Ident(i)) if i == name)
+    }
+
+    fn line(&self) -> u64 {
+        self.tokens
+            .get(self.pos)
+            .or(self.tokens.last())
+            .map(|t| t.line)
+            .unwrap_or(1)
+    }
+
+    /// the value ends before `,` `;` and the closing brackets, or before a word on a new
+    /// line, where js inserts a `;`
+    fn at_value_end(&self) -> bool {
+        match self.peek() {
+            None => true,
+            Some(TokenKind::Punct(',' | ';' | '}' | ']' | ')')) => true,
+            Some(TokenKind::Ident(_)) => {
+                self.pos > 0 && self.tokens[self.pos - 1].line < self.line()
+            }
+            _ => false,
+        }
+    }
+
+    /// skip to the end of the expression starting at `start`, keep its source
+    fn skip_expression(&mut self, start: usize) -> JsValue {
+        self.pos = start;
+        let mut depth = 0usize;
+        while let Some(kind) = self.peek() {
+            match kind {
+                TokenKind::Punct('{' | '[' | '(') => depth += 1,
+                TokenKind::Punct('}' | ']' | ')') if depth > 0 => depth -= 1,
+                TokenKind::Punct(',' | ';' | '}' | ']' | ')') if depth == 0 => break,
+                TokenKind::Ident(_) if depth == 0 && self.pos > start && self.at_value_end() => {
+                    break
+                }
+                _ => {}
+            }
+            self.pos += 1;
+        }
+        let line = self.tokens.get(start).map(|t| t.line).unwrap_or(1);
+        let source = match (
+            self.tokens.get(start),
+            self.tokens.get(self.pos.max(start + 1) - 1),
+        ) {
+            (Some(first), Some(last)) => self.content[first.start..last.end.max(first.start)]
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_owned(),
+            _ => String::new(),
+        };
+        JsValue::Dynamic { line, source }
+    }
+
+    /// skip a type of `as Config` / `satisfies Config` / `const config: Config =`
+    fn skip_type(&mut self) {
+        let mut depth = 0usize;
+        while let Some(kind) = self.peek() {
+            match kind {
+                TokenKind::Punct('{' | '[' | '(') => depth += 1,
+                TokenKind::Punct('}' | ']' | ')') if depth > 0 => depth -= 1,
+                TokenKind::Punct('=') if depth == 0 => break,
+                _ if depth == 0 && self.at_value_end() => break,
+                _ => {}
+            }
+            self.pos += 1;
+        }
+    }
+
+    fn parse_value(&mut self) -> JsValue {
+        let start = self.pos;
+        let Some(value) = self.parse_primary() else {
+            return self.skip_expression(start);
+        };
+        let value = self.parse_member(value, start);
+        while self.is_ident("as") || self.is_ident("satisfies") {
+            self.pos += 1;
+            self.skip_type();
+        }
+        if self.at_value_end() {
+            value
+        } else {
+            self.skip_expression(start)
+        }
+    }
+
+    /// `colors.red` / `colors['red']` / `sizes[0]` of a local constant
+    fn parse_member(&mut self, mut value: JsValue, start: usize) -> JsValue {
+        loop {
+            let key = match (self.peek(), self.peek_at(1), self.peek_at(2)) {
+                (Some(TokenKind::Punct('.')), Some(TokenKind::Ident(key)), _) => key.to_owned(),
+                (
+                    Some(TokenKind::Punct('[')),
+                    Some(TokenKind::Str(key) | TokenKind::Number(key)),
+                    Some(TokenKind::Punct(']')),
+                ) => key.to_owned(),
+                _ => return value,
+            };
+            let member = match &value {
+                JsValue::Object(entries) => entries
+                    .iter()
+                    .rev()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, v)| v.to_owned()),
+                JsValue::Array(values) => key
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|i| values.get(i))
+                    .cloned(),
+                _ => None,
+            };
+            match member {
+                Some(member) => {
+                    value = member;
+                    self.pos += if self.is_punct('.') { 2 } else { 3 };
+                }
+                None => return self.skip_expression(start),
+            }
+        }
+    }
+
+    fn parse_primary(&mut self) -> Option<JsValue> {
+        let token = self.tokens.get(self.pos)?.kind.to_owned();
+        self.pos += 1;
+        match token {
+            TokenKind::Str(s) => Some(JsValue::String(s)),
+            TokenKind::Number(n) => Some(JsValue::Number(n)),
+            TokenKind::Operator(op) if op == "-" => match self.peek() {
+                Some(TokenKind::Number(n)) => {
+                    let n = format!("-{}", n);
+                    self.pos += 1;
+                    Some(JsValue::Number(n))
+                }
+                _ => None,
+            },
+            TokenKind::Punct('{') => self.parse_object(),
+            TokenKind::Punct('[') => self.parse_array(),
+            TokenKind::Ident(name) => match name.as_str() {
+                "true" => Some(JsValue::Bool(true)),
+                "false" => Some(JsValue::Bool(false)),
+                "null" | "undefined" => Some(JsValue::Null),
+                // `defineConfig({ .. })` of unocss / vite returns its argument
+                "defineConfig" if self.is_punct('(') => {
+                    self.pos += 1;
+                    let value = self.parse_value();
+                    if self.is_punct(',') {
+                        self.pos += 1;
+                    }
+                    if self.is_punct(')') {
+                        self.pos += 1;
+                        Some(value)
+                    } else {
+                        None
+                    }
+                }
+                _ if self.is_punct('(') => None,
+                _ => self.bindings.get(&name).cloned(),
+            },
+            _ => None,
+        }
+    }
+
+    fn parse_object(&mut self) -> Option<JsValue> {
+        let mut entries: Vec<(String, JsValue)> = Vec::new();
+        loop {
+            if self.is_punct('}') {
+                self.pos += 1;
+                return Some(JsValue::Object(entries));
+            }
+            let start = self.pos;
+            let token = self.tokens.get(self.pos)?.kind.to_owned();
+            match token {
+                TokenKind::Operator(op) if op == "..." => {
+                    self.pos += 1;
+                    match self.parse_value() {
+                        JsValue::Object(spread) => entries.extend(spread),
+                        JsValue::Dynamic { line, source } => entries.push((
+                            String::new(),
+                            JsValue::Dynamic {
+                                line,
+                                source: format!("...{}", source),
+                            },
+                        )),
+                        _ => {}
+                    }
+                }
+                TokenKind::Ident(key) | TokenKind::Str(key) | TokenKind::Number(key) => {
+                    self.pos += 1;
+                    if self.is_punct(':') {
+                        self.pos += 1;
+                        let value = self.parse_value();
+                        entries.push((key, value));
+                    } else if self.is_punct(',') || self.is_punct('}') {
+                        // `{ colors }` of a local constant
+                        let value = self
+                            .bindings
+                            .get(&key)
+                            .cloned()
+                            .unwrap_or_else(|| self.skip_expression(start));
+                        entries.push((key, value));
+                    } else {
+                        // a method, `theme(config) { .. }`
+                        let value = self.skip_expression(start);
+                        entries.push((key, value));
+                    }
+                }
+                TokenKind::Punct('[') => {
+                    // a computed key, reported with the key of the object
+                    let value = self.skip_expression(start);
+                    entries.push((String::new(), value));
+                }
+                _ => return None,
+            }
+            if self.is_punct(',') {
+                self.pos += 1;
+            } else if !self.is_punct('}') {
+                return None;
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Option<JsValue> {
+        let mut values = Vec::new();
+        loop {
+            if self.is_punct(']') {
+                self.pos += 1;
+                return Some(JsValue::Array(values));
+            }
+            if matches!(self.peek(), Some(TokenKind::Operator(op)) if op == "...") {
+                self.pos += 1;
+                match self.parse_value() {
+                    JsValue::Array(spread) => values.extend(spread),
+                    value => values.push(value),
+                }
+            } else {
+                values.push(self.parse_value());
+            }
+            if self.is_punct(',') {
+                self.pos += 1;
+            } else if !self.is_punct(']') {
+                return None;
+            }
+        }
+    }
+
+    /// the top level statements, the `const` / `let` / `var` are kept for the values that
+    /// use them, the exported config is returned
+    fn parse_module(&mut self) -> Option<JsValue> {
+        let mut export = None;
+        let mut depth = 0usize;
+        while let Some(kind) = self.peek().cloned() {
+            if depth > 0 {
+                match kind {
+                    TokenKind::Punct('{' | '[' | '(') => depth += 1,
+                    TokenKind::Punct('}' | ']' | ')') => depth -= 1,
+                    _ => {}
+                }
+                self.pos += 1;
+                continue;
+            }
+            match kind {
+                TokenKind::Ident(keyword)
+                    if ["const", "let", "var"].contains(&keyword.as_str()) =>
+                {
+                    self.pos += 1;
+                    let Some(TokenKind::Ident(name)) = self.peek().cloned() else {
+                        continue;
+                    };
+                    self.pos += 1;
+                    if self.is_punct(':') {
+                        self.pos += 1;
+                        self.skip_type();
+                    }
+                    if self.is_punct('=') {
+                        self.pos += 1;
+                        let value = self.parse_value();
+                        self.bindings.insert(name, value);
+                    }
+                }
+                TokenKind::Ident(keyword)
+                    if keyword == "export"
+                        && matches!(self.peek_at(1), Some(TokenKind::Ident(d)) if d == "default") =>
+                {
+                    self.pos += 2;
+                    export = Some(self.parse_value());
+                }
+                TokenKind::Ident(keyword)
+                    if keyword == "module"
+                        && self.peek_at(1) == Some(&TokenKind::Punct('.'))
+                        && matches!(self.peek_at(2), Some(TokenKind::Ident(e)) if e == "exports")
+                        && self.peek_at(3) == Some(&TokenKind::Punct('=')) =>
+                {
+                    self.pos += 4;
+                    export = Some(self.parse_value());
+                }
+                TokenKind::Punct('{' | '[' | '(') => {
+                    depth += 1;
+                    self.pos += 1;
+                }
+                _ => self.pos += 1,
+            }
+        }
+        export
+    }
+}
+
+/// the json of a value of the theme, the dynamic parts are skipped and returned in the
+/// warnings with their key, `theme.colors.brand`
+fn to_json(
+    value: JsValue,
+    key: &str,
+    path: &Path,
+    warnings: &mut Vec<RewindError>,
+) -> Option<Value> {
+    match value {
+        JsValue::Null => Some(Value::Null),
+        JsValue::Bool(b) => Some(Value::Bool(b)),
+        JsValue::String(s) => Some(Value::String(s)),
+        JsValue::Number(n) => {
+            let number = n
+                .parse::<i64>()
+                .ok()
+                .map(Number::from)
+                .or_else(|| n.parse::<f64>().ok().and_then(Number::from_f64));
+            match number {
+                Some(number) => Some(Value::Number(number)),
+                None => Some(Value::String(n)),
+            }
+        }
+        JsValue::Array(values) => Some(Value::Array(
+            values
+                .into_iter()
+                .enumerate()
+                .filter_map(|(i, value)| to_json(value, &format!("{}[{}]", key, i), path, warnings))
+                .collect(),
+        )),
+        JsValue::Object(entries) => {
+            let mut map = Map::new();
+            for (entry_key, value) in entries {
+                let entry_path = if entry_key.is_empty() {
+                    key.to_owned()
+                } else {
+                    format!("{}.{}", key, entry_key)
+                };
+                if let Some(value) = to_json(value, &entry_path, path, warnings) {
+                    // a later key of the same name wins and keeps the place of the first, as in js
+                    map.insert(entry_key, value);
+                }
+            }
+            Some(Value::Object(map))
+        }
+        JsValue::Dynamic { line, source } => {
+            warnings.push(RewindError::preset(
+                path,
+                line,
+                format!(
+                    "{}: `{}` can not be read without node, skipped",
+                    key, source
+                ),
+            ));
+            None
+        }
+    }
+}
+
+/// read the `theme` of the object a `tailwind.config.js` / `uno.config.ts` exports with
+/// `export default` or `module.exports`, without running it. object and array literals,
+/// strings, numbers, the local constants and their spreads are read, the other values,
+/// function calls, imports, template strings, are skipped and returned in the warnings.
+/// the result is `{ "theme": .. }` like a json config
+pub fn read_js_config(
+    path: &Path,
+    content: &str,
+    warnings: &mut Vec<RewindError>,
+) -> Result<Value> {
+    let mut parser = Parser {
+        content,
+        tokens: tokenize(content),
+        pos: 0,
+        bindings: HashMap::new(),
+    };
+    let entries = match parser.parse_module() {
+        Some(JsValue::Object(entries)) => entries,
+        Some(JsValue::Dynamic { line, source }) => {
+            return Err(RewindError::preset(
+                path,
+                line,
+                format!(
+                    "the exported config `{}` can not be read without node",
+                    source
+                ),
+            ))
+        }
+        _ => {
+            return Err(RewindError::preset(
+                path,
+                0,
+                "expected an `export default` or `module.exports` object",
+            ))
+        }
+    };
+
+    let mut root = Map::new();
+    if let Some((_, theme)) = entries.into_iter().rev().find(|(key, _)| key == "theme") {
+        if let Some(theme) = to_json(theme, "theme", path, warnings) {
+            root.insert("theme".to_owned(), theme);
+        }
+    }
+    Ok(Value::Object(root))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn read(content: &str) -> (Value, Vec<String>) {
+        let mut warnings = Vec::new();
+        let value =
+            read_js_config(Path::new("tailwind.config.js"), content, &mut warnings).unwrap();
+        (value, warnings.iter().map(|w| w.to_string()).collect())
+    }
+
+    #[test]
+    fn module_exports() {
+        let (value, warnings) = read(
+            r##"
+            /** @type {import('tailwindcss').Config} */
+            module.exports = {
+              content: ["./src/**/*.vue"],
+              theme: { colors: { brand: "#ef4444" }, extend: { spacing: { 18: "4.5rem" } } },
+              plugins: [require("@tailwindcss/forms")],
+            };
+            "##,
+        );
+        assert_eq!(
+            value,
+            json!({ "theme": { "colors": { "brand": "#ef4444" }, "extend": { "spacing": { "18": "4.5rem" } } } })
+        );
+        // the plugins are not part of the theme
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn export_default_define_config() {
+        let (value, _) = read(
+            r##"
+            import { defineConfig } from "unocss";
+            export default defineConfig({
+              theme: { breakpoints: { md: "768px" } },
+            });
+            "##,
+        );
+        assert_eq!(
+            value,
+            json!({ "theme": { "breakpoints": { "md": "768px" } } })
+        );
+    }
+
+    #[test]
+    fn as_and_satisfies() {
+        let (value, _) = read(
+            r##"
+            import type { Config } from "tailwindcss";
+            const colors = { brand: "#ef4444" } as const;
+            export default {
+              theme: { colors } satisfies Config["theme"],
+            } as Config;
+            "##,
+        );
+        assert_eq!(
+            value,
+            json!({ "theme": { "colors": { "brand": "#ef4444" } } })
+        );
+    }
+
+    #[test]
+    fn spreads_and_members_of_local_constants() {
+        let (value, warnings) = read(
+            r##"
+            const palette = { red: { 500: "#ef4444" }, blue: "#3b82f6" };
+            const sizes = ["1rem", "2rem"];
+            module.exports = {
+              theme: {
+                colors: { ...palette, primary: palette.red[500], accent: palette["blue"] },
+                spacing: { sm: sizes[0] },
+              },
+            };
+            "##,
+        );
+        assert_eq!(
+            value,
+            json!({ "theme": {
+                "colors": { "red": { "500": "#ef4444" }, "blue": "#3b82f6", "primary": "#ef4444", "accent": "#3b82f6" },
+                "spacing": { "sm": "1rem" },
+            } })
+        );
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn comments_and_template_strings() {
+        let (value, warnings) = read(
+            r##"
+            // the theme of the card
+            module.exports = {
+              /* colors: { old: "#000" }, */
+              theme: {
+                colors: {
+                  brand: `#ef4444`, // a template without a substitution is a string
+                  "url-like": "http://example.com/*not-a-comment*/",
+                  dynamic: `${base}-500`,
+                },
+              },
+            };
+            "##,
+        );
+        assert_eq!(
+            value,
+            json!({ "theme": { "colors": { "brand": "#ef4444", "url-like": "http://example.com/*not-a-comment*/" } } })
+        );
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert!(
+            warnings[0].contains("theme.colors.dynamic"),
+            "{:?}",
+            warnings
+        );
+    }
+
+    #[test]
+    fn dynamic_values_are_warnings() {
+        let (value, warnings) = read(
+            r##"
+            const defaultTheme = require("tailwindcss/defaultTheme");
+            module.exports = {
+              theme: {
+                fontFamily: { sans: ["Inter", ...defaultTheme.fontFamily.sans] },
+                colors: { brand: getColor("brand"), plain: "#fff" },
+              },
+            };
+            "##,
+        );
+        assert_eq!(value["theme"]["colors"], json!({ "plain": "#fff" }));
+        assert!(
+            warnings.iter().any(|w| w.contains("theme.colors.brand")
+                && w.contains("getColor(\"brand\")")
+                && w.starts_with("tailwind.config.js:")),
+            "{:?}",
+            warnings
+        );
+        assert!(
+            warnings.iter().any(|w| w.contains("theme.fontFamily.sans")),
+            "{:?}",
+            warnings
+        );
+    }
+
+    #[test]
+    fn no_export_is_an_error() {
+        let mut warnings = Vec::new();
+        assert!(read_js_config(Path::new("a.js"), "const a = 1;", &mut warnings).is_err());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::parse::{is_scss_file, is_scss_partial};
+use crate::source_file::{collect_source_files, SourceFile};
+use crate::tailwind_token::TailwindTokenSet;
+use crate::ConvertRun;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::Duration,
+};
+
+/// editors save a file in several steps (truncate, write, rename),
+/// the events within this window are handled as one change
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// the paths a change is converted for. a single input file is watched through its
+/// directory, an editor that saves by renaming a new file over it replaces the file, and
+/// the watch of the old file would end with it
+struct WatchedPaths {
+    /// the input directories, any file inside
+    directories: Vec<PathBuf>,
+    /// the input files, and the partials next to them
+    files: HashSet<PathBuf>,
+    /// the `--load-path` directories, a change there can be used by any scss file
+    load_paths: Vec<PathBuf>,
+}
+
+impl WatchedPaths {
+    fn new(run: &ConvertRun) -> WatchedPaths {
+        let (directories, files): (Vec<&PathBuf>, Vec<&PathBuf>) =
+            run.args.inputs.iter().partition(|input| input.is_dir());
+        WatchedPaths {
+            directories: directories.into_iter().map(|dir| canonical(dir)).collect(),
+            files: files.into_iter().map(|file| canonical(file)).collect(),
+            load_paths: run
+                .scss_options
+                .load_paths
+                .iter()
+                .map(|dir| canonical(dir))
+                .collect(),
+        }
+    }
+
+    /// each directory to watch, once, and whether it is watched recursively
+    fn watch_list(&self) -> Vec<(PathBuf, RecursiveMode)> {
+        let mut list: Vec<(PathBuf, RecursiveMode)> = Vec::new();
+        let recursive = self.directories.iter().chain(&self.load_paths);
+        for dir in recursive {
+            list.push((dir.to_owned(), RecursiveMode::Recursive));
+        }
+        for file in &self.files {
+            let Some(parent) = file.parent() else {
+                continue;
+            };
+            if !list.iter().any(|(dir, _)| parent.starts_with(dir)) {
+                list.push((parent.to_path_buf(), RecursiveMode::NonRecursive));
+            }
+        }
+        list
+    }
+
+    fn is_load_path(&self, path: &Path) -> bool {
+        self.load_paths.iter().any(|dir| path.starts_with(dir))
+    }
+
+    fn contains(&self, path: &Path) -> bool {
+        self.directories.iter().any(|dir| path.starts_with(dir))
+            || self.is_load_path(path)
+            || self.files.contains(path)
+            || (is_scss_partial(path)
+                && self
+                    .files
+                    .iter()
+                    .any(|file| file.parent() == path.parent()))
+    }
+}
+
+fn collect_changed(
+    event: notify::Result<Event>,
+    watched: &WatchedPaths,
+    changed: &mut HashSet<PathBuf>,
+) {
+    if let Ok(event) = event {
+        if let EventKind::Create(_) | EventKind::Modify(_) = event.kind {
+            changed.extend(
+                event
+                    .paths
+                    .iter()
+                    .map(|p| canonical(p))
+                    .filter(|p| watched.contains(p)),
+            );
+        }
+    }
+}
+
+/// `selector [media query] token` of each token, for comparing two runs of the same file
+fn token_keys(resolved_token: &[TailwindTokenSet]) -> BTreeSet<String> {
+    let mut keys = BTreeSet::new();
+    for tw_set in resolved_token {
+        let mut selector = tw_set.involved_classnames.join(", ");
+        if !tw_set.media_query.is_empty() {
+            selector = format!("{} @media({})", selector, tw_set.media_query.join(" "));
+        }
+        for token in &tw_set.tailwind_token {
+            keys.insert(format!("{} {}", selector, token));
+        }
+    }
+    keys
+}
+
+fn print_token_diff(
+    run: &ConvertRun,
+    entry: &SourceFile,
+    previous: &[TailwindTokenSet],
+    current: &[TailwindTokenSet],
+) {
+    if run.verbosity < 0 {
+        return;
+    }
+    let previous_keys = token_keys(previous);
+    let current_keys = token_keys(current);
+    let gained: Vec<&String> = current_keys.difference(&previous_keys).collect();
+    let lost: Vec<&String> = previous_keys.difference(&current_keys).collect();
+
+    eprintln!(
+        "{}: +{} -{} tokens ({} total)",
+        entry.relative_path.display(),
+        gained.len(),
+        lost.len(),
+        current_keys.len()
+    );
+    if run.verbosity >= 1 {
+        for key in gained {
+            eprintln!("  + {}", key);
+        }
+        for key in lost {
+            eprintln!("  - {}", key);
+        }
+    }
+}
+
+/// watch the inputs of `run`, re-convert each changed file and print the token difference
+/// against `last_result`. the theme of `run` is reused between the runs.
+pub fn watch_and_convert(
+    run: &ConvertRun,
+    mut last_result: HashMap<PathBuf, Vec<TailwindTokenSet>>,
+) {
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(tx) {
+        Ok(watcher) => watcher,
+        Err(err) => {
+            eprintln!("cannot start watching: {}", err);
+            return;
+        }
+    };
+    let watched = WatchedPaths::new(run);
+    for (dir, mode) in watched.watch_list() {
+        if let Err(err) = watcher.watch(&dir, mode) {
+            eprintln!("cannot watch {}: {}", dir.display(), err);
+        }
+    }
+    if run.verbosity >= 0 {
+        eprintln!("watching for changes, press ctrl-c to stop");
+    }
+
+    while let Ok(event) = rx.recv() {
+        let mut changed: HashSet<PathBuf> = HashSet::new();
+        collect_changed(event, &watched, &mut changed);
+        while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+            collect_changed(event, &watched, &mut changed);
+        }
+        if changed.is_empty() {
+            continue;
+        }
+
+        let file_list =
+            match collect_source_files(&run.args.inputs, &run.args.include, &run.args.exclude) {
+                Ok(file_list) => file_list,
+                Err(_) => continue,
+            };
+        // a partial, or a file of a load path, can be used by any other scss file
+        let partial_changed = changed
+            .iter()
+            .any(|p| is_scss_partial(p) || watched.is_load_path(p));
+
+        for entry in file_list.iter().filter(|entry| {
+            !is_scss_partial(&entry.path)
+                && (changed.contains(&canonical(&entry.path))
+                    || (partial_changed && is_scss_file(&entry.path)))
+        }) {
+            if let Ok(resolved_token) = run.convert_file(entry) {
+                let previous = last_result
+                    .insert(entry.path.to_owned(), resolved_token)
+                    .unwrap_or_default();
+                print_token_diff(run, entry, &previous, &last_result[&entry.path]);
+            }
+        }
+    }
+}